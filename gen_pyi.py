@@ -62,6 +62,7 @@ with open("pyhornedowl/py.typed", "w"):
 
 with open("pyhornedowl/model/__init__.py", "w") as f:
     f.write("from ..pyhornedowl import model\n")
+    f.write("from ._constructor import constructor\n")
     f.write("import typing\n\n")
 
     al = []
@@ -70,7 +71,11 @@ with open("pyhornedowl/model/__init__.py", "w") as f:
         if not isinstance(entry, type) and not type(entry) == typing._UnionGenericAlias:
             continue
 
-        f.write(f"{name} = model.{name}\n")
+        # IRIs and facets are not built from fields, so they cannot be patterns
+        if isinstance(entry, type) and name not in ("IRI", "Facet"):
+            f.write(f"{name} = constructor(model.{name})\n")
+        else:
+            f.write(f"{name} = model.{name}\n")
         al.append(name)
 
     # module_pyi = pyhornedowl.model.__pyi__()
@@ -1,4 +1,4 @@
 from __future__ import annotations
-from .pyhornedowl import PyIndexedOntology, open_ontology, get_descendants, get_ancestors
+from .pyhornedowl import PyIndexedOntology, AxiomIterator, Var, Pattern, open_ontology, get_descendants, get_ancestors, get_property_descendants, get_property_ancestors
 
-__all__ = ["PyIndexedOntology", "open_ontology", "get_descendants", "get_ancestors"]
+__all__ = ["PyIndexedOntology", "AxiomIterator", "Var", "Pattern", "open_ontology", "get_descendants", "get_ancestors", "get_property_descendants", "get_property_ancestors"]
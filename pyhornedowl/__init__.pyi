@@ -4,6 +4,12 @@ from typing import *
 import model
 
 class PyIndexedOntology:
+    def __init__(self) -> None:
+        """
+        Creates an empty ontology.
+        """
+        ...
+
     def get_id_for_iri(self, iri: str) -> Optional[str]:
         """
         Gets the ID of term by it IRI.
@@ -30,7 +36,34 @@ class PyIndexedOntology:
         """
         Sets the label of a term by iri.
         
-        Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`.
+        Adds or replaces the `AnnotationAssertion` axioms for `rdfs:label`.
+        """
+        ...
+
+    def set_annotation(self, iri: str, property: str, value: Union[str, model.IRI, model.Literal], lang: Optional[str] = None, datatype: Optional[str] = None, replace: bool = True) -> None:
+        """
+        Sets the value of an annotation property for a term by iri.
+        
+        A string `value` is stored as a simple literal, as a language tagged literal if `lang` is given
+        or as a typed literal if `datatype` is given. `model.IRI` and `model.Literal` values are stored as they are.
+        If `replace` is true, the existing values of the annotation property in the same language are removed first:
+        values with the same language tag for a language tagged literal and values without a language tag otherwise.
+        """
+        ...
+
+    def add_annotation(self, iri: str, property: str, value: Union[str, model.IRI, model.Literal], lang: Optional[str] = None, datatype: Optional[str] = None) -> None:
+        """
+        Adds a value for an annotation property to a term by iri.
+        
+        Existing values of the annotation property are kept. See `PyIndexedOntology.set_annotation` for the handling of `value`.
+        """
+        ...
+
+    def remove_annotations(self, iri: str, property: str) -> int:
+        """
+        Removes all values of an annotation property from a term by iri.
+        
+        Returns the number of removed `AnnotationAssertion` axioms.
         """
         ...
 
@@ -54,6 +87,30 @@ class PyIndexedOntology:
         """
         ...
 
+    def set_iri(self, iri: Optional[str]) -> None:
+        """
+        Sets the ontology iri. If `iri` is `None`, the ontology iri is removed.
+        """
+        ...
+
+    def set_version_iri(self, iri: Optional[str]) -> None:
+        """
+        Sets the ontologys version iri. If `iri` is `None`, the version iri is removed.
+        """
+        ...
+
+    def get_ontology_annotations(self) -> List[model.Annotation]:
+        """
+        Returns the annotations of the ontology itself, e.g. title, license or description.
+        """
+        ...
+
+    def set_ontology_annotations(self, annotations: List[model.Annotation]) -> None:
+        """
+        Replaces all annotations of the ontology itself.
+        """
+        ...
+
     def get_subclasses(self, iri: str) -> Set[str]:
         """
         Gets all subclasses of an entity.
@@ -66,6 +123,110 @@ class PyIndexedOntology:
         """
         ...
 
+    def set_hierarchy_mode(self, mode: str) -> None:
+        """
+        Sets which axioms build the class hierarchy and rebuilds it.
+        
+        In the default mode `"asserted"` only `SubClassOf` axioms between named classes are used. In the mode
+        `"equivalents"`, named classes in `EquivalentClasses` axioms are additionally parents of each other and
+        the named conjuncts of an `ObjectIntersectionOf` in `EquivalentClasses` or `SubClassOf` axioms are parents as well.
+        """
+        ...
+
+    def get_hierarchy_mode(self) -> str:
+        """
+        Returns the mode used to build the class hierarchy, either `"asserted"` or `"equivalents"`.
+        """
+        ...
+
+    def get_sub_properties(self, iri: str) -> Set[str]:
+        """
+        Gets the direct sub properties of an object, data or annotation property.
+        """
+        ...
+
+    def get_super_properties(self, iri: str) -> Set[str]:
+        """
+        Gets the direct super properties of an object, data or annotation property.
+        """
+        ...
+
+    def get_inverse(self, iri: str) -> Set[str]:
+        """
+        Gets the object properties declared inverse of an object property by `InverseObjectProperties` axioms.
+        """
+        ...
+
+    def get_property_chains(self, iri: str) -> List[List[str]]:
+        """
+        Gets the property chains that imply an object property.
+        
+        Chains containing inverse object properties are not included.
+        """
+        ...
+
+    def to_edge_list(self, relations: Optional[List[str]] = None, use_labels: bool = False) -> List[Tuple[str, str, str]]:
+        """
+        Gets the edges between named classes as `(source, predicate, target)` tuples.
+        
+        The predicate `"subClassOf"` gives the edges of the class hierarchy as returned by `get_superclasses`,
+        so they depend on the hierarchy mode. Other predicates are object properties relating the classes as
+        returned by `get_related`, which includes relations from conjuncts of intersections and from equivalent
+        class expressions. If `relations` is given,
+        only edges with these predicates are returned. With `use_labels`, classes and properties are given by their
+        label if they have one.
+        """
+        ...
+
+    def to_networkx(self, relations: Optional[List[str]] = None, use_labels: bool = False, multi: bool = False) -> typing.Any:
+        """
+        Builds a `networkx.DiGraph` from the edges returned by `to_edge_list`.
+        
+        The predicate of an edge is stored in its attribute `predicate`. A `DiGraph` keeps a single edge between
+        two classes, so with `multi` a `networkx.MultiDiGraph` keyed by predicate is built instead.
+        """
+        ...
+
+    def to_table(self, columns: List[str] = ["iri", "curie", "label", "definition", "parents"], path: Optional[str] = None) -> List[Dict[str, str]]:
+        """
+        Gets a row for each declared class, ordered by IRI, with the values of `columns`.
+        
+        The columns `"iri"`, `"curie"`, `"label"` and `"definition"` hold the IRI, CURIE, label and definition of the
+        class, `"parents"` its direct superclasses by label or CURIE. Any other column holds the values of the
+        annotation property with that IRI or CURIE, or the declared annotation property with that label. Several values
+        of a cell are separated by `|`.
+        
+        If `path` is given, the table is written to that file as well, tab separated if it ends in `.tsv` and comma
+        separated otherwise.
+        """
+        ...
+
+    def get_related(self, iri: str, property: str) -> Set[str]:
+        """
+        Gets the classes directly related to a class by an object property.
+        
+        A class `A` is related to `B` by `R` if `A` is a subclass of `ObjectSomeValuesFrom(R, B)`, either
+        directly or as a conjunct of an `ObjectIntersectionOf`, or if `A` is equivalent to such a class expression.
+        """
+        ...
+
+    def get_relations(self, iri: str) -> Dict[str, Set[str]]:
+        """
+        Gets the classes directly related to a class by each object property, as described for `get_related`.
+        """
+        ...
+
+    def get_related_transitive(self, iri: str, property: str, include_is_a: bool = True) -> Set[str]:
+        """
+        Gets the classes related to a class by a chain of `property` edges, treating the property as transitive.
+        
+        With `include_is_a`, subclass edges may occur anywhere along the chain, so a class inherits the relations
+        of its superclasses and is also related to the superclasses of its related classes. The subclass edges are
+        those of `get_superclasses`, so with the hierarchy mode `"equivalents"` they include edges derived from
+        equivalent classes and intersections.
+        """
+        ...
+
     def get_classes(self) -> Set[str]:
         """
         Returns the IRIs of all declared classes in the ontology.
@@ -78,6 +239,74 @@ class PyIndexedOntology:
         """
         ...
 
+    def get_individuals(self) -> Set[str]:
+        """
+        Returns the IRIs of all named individuals in the ontology.
+        
+        Besides declared individuals, this includes named individuals used in class or property assertions.
+        """
+        ...
+
+    def get_types(self, individual: str) -> Set[str]:
+        """
+        Gets the named classes an individual is asserted to be an instance of.
+        """
+        ...
+
+    def get_instances(self, iri: str, direct: bool = True) -> Set[str]:
+        """
+        Gets the named individuals asserted to be instances of a class.
+        
+        If `direct` is false, the instances of all subclasses of the class are included as well.
+        """
+        ...
+
+    def get_object_property_values(self, individual: str, property: str) -> Set[str]:
+        """
+        Gets the named individuals an individual is connected to by an object property.
+        
+        Assertions using the inverse of the object property are taken into account.
+        """
+        ...
+
+    def get_data_property_values(self, individual: str, property: str) -> List[model.Literal]:
+        """
+        Gets the literals an individual is connected to by a data property.
+        """
+        ...
+
+    def get_data_properties(self) -> Set[str]:
+        """
+        Returns the IRIs of all declared data properties in the ontology.
+        """
+        ...
+
+    def get_annotation_properties(self) -> Set[str]:
+        """
+        Returns the IRIs of all declared annotation properties in the ontology.
+        """
+        ...
+
+    def get_named_individuals(self) -> Set[str]:
+        """
+        Returns the IRIs of all declared named individuals in the ontology.
+        """
+        ...
+
+    def get_datatypes(self) -> Set[str]:
+        """
+        Returns the IRIs of all declared datatypes in the ontology.
+        """
+        ...
+
+    def get_signature(self, include_undeclared: bool = True) -> List[Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]:
+        """
+        Returns all entities of the ontology as model objects, sorted by their kind and IRI.
+        
+        If `include_undeclared` is true, entities that are used in axioms but never declared are included as well.
+        """
+        ...
+
     def get_annotation(self, class_iri: str, ann_iri: str) -> Optional[str]:
         """
         Gets the first annotated value for an entity and annotation property.
@@ -97,9 +326,21 @@ class PyIndexedOntology:
         """
         ...
 
-    def save_to_file(self, file_name: str) -> None:
+    def pretty(self, obj: typing.Any, width: int = 80) -> str:
         """
-        Saves the ontology to disk in owx format.
+        Renders a model object with the labels of the entities it references.
+        
+        Entities without a label are shown by their IRI, lines longer than `width` are broken
+        between the arguments of nested objects.
+        """
+        ...
+
+    def save_to_file(self, file_name: str, format: Optional[str] = None) -> None:
+        """
+        Saves the ontology to disk in owx format or, if `format` is `"obographs"`, as OBO Graphs JSON.
+        
+        Without `format`, files ending in `.json` are saved as OBO Graphs JSON.
+        The ontology iri, version iri and ontology annotations are written as well.
         """
         ...
 
@@ -115,15 +356,163 @@ class PyIndexedOntology:
         """
         ...
 
+    def iter_axioms(self) -> Iterator[model.AnnotatedAxiom]:
+        """
+        Iterates over all axioms of the ontology.
+        
+        Unlike `PyIndexedOntology.get_axioms`, the axioms are converted to Python objects one at a time.
+        Changing the axioms of the ontology while iterating raises a `RuntimeError`.
+        """
+        ...
+
+    def iter_axioms_for_iri(self, iri: str) -> Iterator[model.AnnotatedAxiom]:
+        """
+        Iterates over all axioms for an entity.
+        
+        Like `PyIndexedOntology.iter_axioms`, the axioms are converted to Python objects one at a time.
+        """
+        ...
+
+    def get_axioms_of_kind(self, kind: Union[str, type]) -> List[model.AnnotatedAxiom]:
+        """
+        Returns all axioms of a kind.
+        
+        The kind is given by the name of the axiom class, e.g. `"SubClassOf"`, or by the class itself, e.g. `model.SubClassOf`.
+        """
+        ...
+
+    def count_axioms_by_kind(self) -> Dict[str, int]:
+        """
+        Returns the number of axioms for each axiom kind occurring in the ontology.
+        """
+        ...
+
+    def match_axioms(self, pattern: Union[Pattern, model.Axiom, model.AnnotatedAxiom]) -> List[Dict[str, typing.Any]]:
+        """
+        Finds all axioms matching the pattern and returns the variable bindings of each match.
+        
+        Only axioms of the kind given by the pattern are considered. Patterns of kind
+        `AnnotatedAxiom` match the axioms together with their annotations.
+        
+        An axiom yields one result for every distinct way it matches, so a pattern over an
+        unordered list such as `DisjointClasses([Var("x"), Var("y")])` yields each assignment
+        of the classes to `x` and `y`.
+        """
+        ...
+
+    def sparql(self, query: str) -> List[tuple]:
+        """
+        Evaluates a SPARQL SELECT query over the RDF representation of the ontology.
+        
+        Supports basic graph patterns, `FILTER`, `OPTIONAL`, `DISTINCT`, `LIMIT` and `OFFSET`.
+        Prefixes not declared in the query are expanded with the prefix mappings of the ontology.
+        Each row holds the values of the selected variables: IRIs as strings, blank nodes as
+        `_:id`, literals as Python values and unbound variables as `None`.
+        """
+        ...
+
+    def get_referencing_axioms(self, iri: str, role: str = "any") -> List[model.AnnotatedAxiom]:
+        """
+        Gets the axioms referencing an entity in the given role.
+        
+        With `role="subject"` only the axioms about the entity are returned, e.g. its declaration,
+        its annotations or the axioms with the entity as subclass or asserted individual. With
+        `role="filler"` only the axioms using the entity anywhere else are returned, e.g. as a
+        superclass, nested in a class expression or in an axiom annotation. `role="any"` returns both.
+        """
+        ...
+
+    def usage_count(self, iri: str) -> int:
+        """
+        Counts the axioms using an entity as a filler.
+        
+        These are the axioms which would be affected by deleting the entity apart from the
+        axioms about the entity itself. See `PyIndexedOntology.get_referencing_axioms`.
+        """
+        ...
+
+    def rename_entity(self, old_iri: str, new_iri: str) -> int:
+        """
+        Replaces every occurrence of an IRI in the ontology.
+        
+        See `PyIndexedOntology.rename_entities`.
+        """
+        ...
+
+    def rename_entities(self, mapping: Dict[str, str]) -> int:
+        """
+        Replaces every occurrence of the IRIs in `mapping` by the IRIs they map to.
+        
+        Rewrites all axioms including nested class expressions, annotation subjects and values
+        and axiom annotations. All IRIs are replaced at once, so the mapping may swap IRIs or
+        map an IRI to another renamed IRI. Axioms which become equal after renaming are merged.
+        Returns the number of rewritten axioms.
+        """
+        ...
+
+    def remove_entity(self, iri: str, cascade: bool = True) -> List[model.AnnotatedAxiom]:
+        """
+        Removes an entity from the ontology and returns the removed axioms.
+        
+        Removes the declarations of the entity and the annotation assertions about it. If `cascade`
+        is set, the logical axioms using the entity are removed as well. Annotation assertions of
+        other terms with the entity as value and axioms only using it in their annotations are kept.
+        """
+        ...
+
+    def obsolete(self, iri: str, replaced_by: Optional[str] = None, consider: List[str] = [], reason: Optional[Union[str, model.IRI, model.Literal]] = None) -> List[model.AnnotatedAxiom]:
+        """
+        Marks a term as obsolete following the OBO conventions and returns the removed axioms.
+        
+        Sets `owl:deprecated` to true, prefixes the labels of the term with "obsolete " and removes
+        all logical axioms using the term. `replaced_by` is set as IAO:0100001 (term replaced by),
+        each term in `consider` is added as oboInOwl:consider and `reason` is set as IAO:0000231
+        (has obsolescence reason). Terms may be given as IRIs or CURIEs. A `reason` string which is an
+        IRI or a CURIE such as `IAO:0000227` is stored as IRI, other values as described for
+        `PyIndexedOntology.set_annotation`.
+        
+        CURIEs are expanded with the prefix mappings of the ontology. CURIEs of OBO ontologies such
+        as GO or IAO are expanded to OBO PURLs without a mapping. A `ValueError` is raised for
+        other CURIEs without a prefix mapping, before the ontology is changed.
+        """
+        ...
+
+    def get_obsolete_terms(self) -> Set[str]:
+        """
+        Gets the IRIs of all terms with `owl:deprecated` set to true.
+        """
+        ...
+
     def add_axiom(self, ax: model.Axiom, annotations: Optional[List[model.Annotation]]) -> None:
         """
         Adds an axiom to the ontology with optional annotations.
         """
         ...
 
-    def remove_axiom(self, ax: model.Axiom) -> None:
+    def add_axioms(self, axioms: Iterable[Union[model.Axiom, model.AnnotatedAxiom]]) -> int:
+        """
+        Adds all axioms of an iterable to the ontology and returns the number of added axioms.
+        
+        The iterable is consumed lazily, so generators can be used. The GIL is released while the
+        axioms are inserted and indexed.
+        """
+        ...
+
+    def remove_axiom(self, ax: Union[model.Axiom, model.AnnotatedAxiom]) -> int:
         """
-        Removes an axiom from the ontology.
+        Removes an axiom from the ontology and returns the number of removed axioms.
+        
+        A `model.AnnotatedAxiom` is only removed if its annotations match as well. A `model.Axiom`
+        removes the axiom with any annotations.
+        """
+        ...
+
+    def remove_axioms(self, axioms: Iterable[Union[model.Axiom, model.AnnotatedAxiom]]) -> int:
+        """
+        Removes all axioms of an iterable from the ontology and returns the number of removed axioms.
+        
+        Axioms are matched as in `PyIndexedOntology.remove_axiom`. The iterable is consumed lazily,
+        so generators can be used. The GIL is released while the axioms are removed.
         """
         ...
 
@@ -136,26 +525,63 @@ class PyIndexedOntology:
         ...
 
 
+class AxiomIterator:
+    ...
+
+class Var:
+    ...
+
+class Pattern:
+    ...
+
 def open_ontology(ontology: str) -> PyIndexedOntology:
     """
     Opens an ontology from a path or plain text.
     
-    If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx or owl format.
-    Note: Only .owl and .owx files are currently supported.
+    If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx, owl or OBO Graphs JSON format.
+    Note: Only .owl, .owx and .json (OBO Graphs) files are currently supported.
     """
      ..
 
 
-def get_descendants(onto: PyIndexedOntology, parent: str) -> Set[str]:
+def get_descendants(onto: PyIndexedOntology, parent: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]:
     """
-    Gets all direct and indirect subclasses of an class.
+    Gets all direct and indirect subclasses of a class.
+    
+    `parent` itself is only included if `include_self` is true. Earlier versions always included it,
+    pass `include_self=True` to keep that behaviour.
+    If `max_depth` is given, only subclasses at most that many levels below `parent` are returned.
+    Cycles in the class hierarchy do not stop the traversal but are reported with a `RuntimeWarning`.
     """
      ..
 
 
-def get_ancestors(onto: PyIndexedOntology, child: str) -> Set[str]:
+def get_ancestors(onto: PyIndexedOntology, child: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]:
     """
     Gets all direct and indirect super classes of a class.
+    
+    `child` itself is only included if `include_self` is true. Earlier versions always included it,
+    pass `include_self=True` to keep that behaviour.
+    If `max_depth` is given, only super classes at most that many levels above `child` are returned.
+    Cycles in the class hierarchy do not stop the traversal but are reported with a `RuntimeWarning`.
+    """
+     ..
+
+
+def get_property_descendants(onto: PyIndexedOntology, parent: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]:
+    """
+    Gets all direct and indirect sub properties of a property.
+    
+    Behaves like `get_descendants` but over the property hierarchy.
+    """
+     ..
+
+
+def get_property_ancestors(onto: PyIndexedOntology, child: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]:
+    """
+    Gets all direct and indirect super properties of a property.
+    
+    Behaves like `get_ancestors` but over the property hierarchy.
     """
      ..
 
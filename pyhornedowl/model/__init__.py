@@ -1,87 +1,88 @@
 from ..pyhornedowl import model
+from ._constructor import constructor
 import typing
 
-Class = model.Class
-ObjectIntersectionOf = model.ObjectIntersectionOf
-ObjectUnionOf = model.ObjectUnionOf
-ObjectComplementOf = model.ObjectComplementOf
-ObjectOneOf = model.ObjectOneOf
-ObjectSomeValuesFrom = model.ObjectSomeValuesFrom
-ObjectAllValuesFrom = model.ObjectAllValuesFrom
-ObjectHasValue = model.ObjectHasValue
-ObjectHasSelf = model.ObjectHasSelf
-ObjectMinCardinality = model.ObjectMinCardinality
-ObjectMaxCardinality = model.ObjectMaxCardinality
-ObjectExactCardinality = model.ObjectExactCardinality
-DataSomeValuesFrom = model.DataSomeValuesFrom
-DataAllValuesFrom = model.DataAllValuesFrom
-DataHasValue = model.DataHasValue
-DataMinCardinality = model.DataMinCardinality
-DataMaxCardinality = model.DataMaxCardinality
-DataExactCardinality = model.DataExactCardinality
-Datatype = model.Datatype
-DataIntersectionOf = model.DataIntersectionOf
-DataUnionOf = model.DataUnionOf
-DataComplementOf = model.DataComplementOf
-DataOneOf = model.DataOneOf
-DatatypeRestriction = model.DatatypeRestriction
-SimpleLiteral = model.SimpleLiteral
-LanguageLiteral = model.LanguageLiteral
-DatatypeLiteral = model.DatatypeLiteral
-ObjectProperty = model.ObjectProperty
-InverseObjectProperty = model.InverseObjectProperty
-AnnotatedAxiom = model.AnnotatedAxiom
-Annotation = model.Annotation
-AnnotationAssertion = model.AnnotationAssertion
-AnnotationProperty = model.AnnotationProperty
-AnnotationPropertyDomain = model.AnnotationPropertyDomain
-AnnotationPropertyRange = model.AnnotationPropertyRange
-AnonymousIndividual = model.AnonymousIndividual
-AsymmetricObjectProperty = model.AsymmetricObjectProperty
-ClassAssertion = model.ClassAssertion
-DataProperty = model.DataProperty
-DataPropertyAssertion = model.DataPropertyAssertion
-DataPropertyDomain = model.DataPropertyDomain
-DataPropertyRange = model.DataPropertyRange
-DatatypeDefinition = model.DatatypeDefinition
-DeclareAnnotationProperty = model.DeclareAnnotationProperty
-DeclareClass = model.DeclareClass
-DeclareDataProperty = model.DeclareDataProperty
-DeclareDatatype = model.DeclareDatatype
-DeclareNamedIndividual = model.DeclareNamedIndividual
-DeclareObjectProperty = model.DeclareObjectProperty
-DifferentIndividuals = model.DifferentIndividuals
-DisjointClasses = model.DisjointClasses
-DisjointDataProperties = model.DisjointDataProperties
-DisjointObjectProperties = model.DisjointObjectProperties
-DisjointUnion = model.DisjointUnion
-EquivalentClasses = model.EquivalentClasses
-EquivalentDataProperties = model.EquivalentDataProperties
-EquivalentObjectProperties = model.EquivalentObjectProperties
-FacetRestriction = model.FacetRestriction
-FunctionalDataProperty = model.FunctionalDataProperty
-FunctionalObjectProperty = model.FunctionalObjectProperty
-HasKey = model.HasKey
+Class = constructor(model.Class)
+ObjectIntersectionOf = constructor(model.ObjectIntersectionOf)
+ObjectUnionOf = constructor(model.ObjectUnionOf)
+ObjectComplementOf = constructor(model.ObjectComplementOf)
+ObjectOneOf = constructor(model.ObjectOneOf)
+ObjectSomeValuesFrom = constructor(model.ObjectSomeValuesFrom)
+ObjectAllValuesFrom = constructor(model.ObjectAllValuesFrom)
+ObjectHasValue = constructor(model.ObjectHasValue)
+ObjectHasSelf = constructor(model.ObjectHasSelf)
+ObjectMinCardinality = constructor(model.ObjectMinCardinality)
+ObjectMaxCardinality = constructor(model.ObjectMaxCardinality)
+ObjectExactCardinality = constructor(model.ObjectExactCardinality)
+DataSomeValuesFrom = constructor(model.DataSomeValuesFrom)
+DataAllValuesFrom = constructor(model.DataAllValuesFrom)
+DataHasValue = constructor(model.DataHasValue)
+DataMinCardinality = constructor(model.DataMinCardinality)
+DataMaxCardinality = constructor(model.DataMaxCardinality)
+DataExactCardinality = constructor(model.DataExactCardinality)
+Datatype = constructor(model.Datatype)
+DataIntersectionOf = constructor(model.DataIntersectionOf)
+DataUnionOf = constructor(model.DataUnionOf)
+DataComplementOf = constructor(model.DataComplementOf)
+DataOneOf = constructor(model.DataOneOf)
+DatatypeRestriction = constructor(model.DatatypeRestriction)
+SimpleLiteral = constructor(model.SimpleLiteral)
+LanguageLiteral = constructor(model.LanguageLiteral)
+DatatypeLiteral = constructor(model.DatatypeLiteral)
+ObjectProperty = constructor(model.ObjectProperty)
+InverseObjectProperty = constructor(model.InverseObjectProperty)
+AnnotatedAxiom = constructor(model.AnnotatedAxiom)
+Annotation = constructor(model.Annotation)
+AnnotationAssertion = constructor(model.AnnotationAssertion)
+AnnotationProperty = constructor(model.AnnotationProperty)
+AnnotationPropertyDomain = constructor(model.AnnotationPropertyDomain)
+AnnotationPropertyRange = constructor(model.AnnotationPropertyRange)
+AnonymousIndividual = constructor(model.AnonymousIndividual)
+AsymmetricObjectProperty = constructor(model.AsymmetricObjectProperty)
+ClassAssertion = constructor(model.ClassAssertion)
+DataProperty = constructor(model.DataProperty)
+DataPropertyAssertion = constructor(model.DataPropertyAssertion)
+DataPropertyDomain = constructor(model.DataPropertyDomain)
+DataPropertyRange = constructor(model.DataPropertyRange)
+DatatypeDefinition = constructor(model.DatatypeDefinition)
+DeclareAnnotationProperty = constructor(model.DeclareAnnotationProperty)
+DeclareClass = constructor(model.DeclareClass)
+DeclareDataProperty = constructor(model.DeclareDataProperty)
+DeclareDatatype = constructor(model.DeclareDatatype)
+DeclareNamedIndividual = constructor(model.DeclareNamedIndividual)
+DeclareObjectProperty = constructor(model.DeclareObjectProperty)
+DifferentIndividuals = constructor(model.DifferentIndividuals)
+DisjointClasses = constructor(model.DisjointClasses)
+DisjointDataProperties = constructor(model.DisjointDataProperties)
+DisjointObjectProperties = constructor(model.DisjointObjectProperties)
+DisjointUnion = constructor(model.DisjointUnion)
+EquivalentClasses = constructor(model.EquivalentClasses)
+EquivalentDataProperties = constructor(model.EquivalentDataProperties)
+EquivalentObjectProperties = constructor(model.EquivalentObjectProperties)
+FacetRestriction = constructor(model.FacetRestriction)
+FunctionalDataProperty = constructor(model.FunctionalDataProperty)
+FunctionalObjectProperty = constructor(model.FunctionalObjectProperty)
+HasKey = constructor(model.HasKey)
 IRI = model.IRI
-Import = model.Import
-InverseFunctionalObjectProperty = model.InverseFunctionalObjectProperty
-InverseObjectProperties = model.InverseObjectProperties
-IrreflexiveObjectProperty = model.IrreflexiveObjectProperty
-NamedIndividual = model.NamedIndividual
-NegativeDataPropertyAssertion = model.NegativeDataPropertyAssertion
-NegativeObjectPropertyAssertion = model.NegativeObjectPropertyAssertion
-ObjectPropertyAssertion = model.ObjectPropertyAssertion
-ObjectPropertyDomain = model.ObjectPropertyDomain
-ObjectPropertyRange = model.ObjectPropertyRange
-OntologyAnnotation = model.OntologyAnnotation
-ReflexiveObjectProperty = model.ReflexiveObjectProperty
-SameIndividual = model.SameIndividual
-SubAnnotationPropertyOf = model.SubAnnotationPropertyOf
-SubClassOf = model.SubClassOf
-SubDataPropertyOf = model.SubDataPropertyOf
-SubObjectPropertyOf = model.SubObjectPropertyOf
-SymmetricObjectProperty = model.SymmetricObjectProperty
-TransitiveObjectProperty = model.TransitiveObjectProperty
+Import = constructor(model.Import)
+InverseFunctionalObjectProperty = constructor(model.InverseFunctionalObjectProperty)
+InverseObjectProperties = constructor(model.InverseObjectProperties)
+IrreflexiveObjectProperty = constructor(model.IrreflexiveObjectProperty)
+NamedIndividual = constructor(model.NamedIndividual)
+NegativeDataPropertyAssertion = constructor(model.NegativeDataPropertyAssertion)
+NegativeObjectPropertyAssertion = constructor(model.NegativeObjectPropertyAssertion)
+ObjectPropertyAssertion = constructor(model.ObjectPropertyAssertion)
+ObjectPropertyDomain = constructor(model.ObjectPropertyDomain)
+ObjectPropertyRange = constructor(model.ObjectPropertyRange)
+OntologyAnnotation = constructor(model.OntologyAnnotation)
+ReflexiveObjectProperty = constructor(model.ReflexiveObjectProperty)
+SameIndividual = constructor(model.SameIndividual)
+SubAnnotationPropertyOf = constructor(model.SubAnnotationPropertyOf)
+SubClassOf = constructor(model.SubClassOf)
+SubDataPropertyOf = constructor(model.SubDataPropertyOf)
+SubObjectPropertyOf = constructor(model.SubObjectPropertyOf)
+SymmetricObjectProperty = constructor(model.SymmetricObjectProperty)
+TransitiveObjectProperty = constructor(model.TransitiveObjectProperty)
 Facet = model.Facet
 ClassExpression = model.ClassExpression
 ObjectPropertyExpression = model.ObjectPropertyExpression
@@ -5,30 +5,80 @@ class Class:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> Class:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> Class:
+        ...
     ...
 
 class ObjectIntersectionOf:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectIntersectionOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectIntersectionOf:
+        ...
     ...
 
 class ObjectUnionOf:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectUnionOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectUnionOf:
+        ...
     ...
 
 class ObjectComplementOf:
     first: ClassExpression
     def __init__(self, first: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectComplementOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectComplementOf:
+        ...
     ...
 
 class ObjectOneOf:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectOneOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectOneOf:
+        ...
     ...
 
 class ObjectSomeValuesFrom:
@@ -36,6 +86,16 @@ class ObjectSomeValuesFrom:
     bce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectSomeValuesFrom:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectSomeValuesFrom:
+        ...
     ...
 
 class ObjectAllValuesFrom:
@@ -43,6 +103,16 @@ class ObjectAllValuesFrom:
     bce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectAllValuesFrom:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectAllValuesFrom:
+        ...
     ...
 
 class ObjectHasValue:
@@ -50,12 +120,32 @@ class ObjectHasValue:
     i: Individual
     def __init__(self, ope: ObjectPropertyExpression, i: Individual):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectHasValue:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectHasValue:
+        ...
     ...
 
 class ObjectHasSelf:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectHasSelf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectHasSelf:
+        ...
     ...
 
 class ObjectMinCardinality:
@@ -64,6 +154,16 @@ class ObjectMinCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectMinCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectMinCardinality:
+        ...
     ...
 
 class ObjectMaxCardinality:
@@ -72,6 +172,16 @@ class ObjectMaxCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectMaxCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectMaxCardinality:
+        ...
     ...
 
 class ObjectExactCardinality:
@@ -80,6 +190,16 @@ class ObjectExactCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectExactCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectExactCardinality:
+        ...
     ...
 
 class DataSomeValuesFrom:
@@ -87,6 +207,16 @@ class DataSomeValuesFrom:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataSomeValuesFrom:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataSomeValuesFrom:
+        ...
     ...
 
 class DataAllValuesFrom:
@@ -94,6 +224,16 @@ class DataAllValuesFrom:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataAllValuesFrom:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataAllValuesFrom:
+        ...
     ...
 
 class DataHasValue:
@@ -101,6 +241,16 @@ class DataHasValue:
     l: Literal
     def __init__(self, dp: DataProperty, l: Literal):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataHasValue:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataHasValue:
+        ...
     ...
 
 class DataMinCardinality:
@@ -109,6 +259,16 @@ class DataMinCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataMinCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataMinCardinality:
+        ...
     ...
 
 class DataMaxCardinality:
@@ -117,6 +277,16 @@ class DataMaxCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataMaxCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataMaxCardinality:
+        ...
     ...
 
 class DataExactCardinality:
@@ -125,36 +295,96 @@ class DataExactCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataExactCardinality:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataExactCardinality:
+        ...
     ...
 
 class Datatype:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> Datatype:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> Datatype:
+        ...
     ...
 
 class DataIntersectionOf:
     first: typing.List[DataRange]
     def __init__(self, first: typing.List[DataRange]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataIntersectionOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataIntersectionOf:
+        ...
     ...
 
 class DataUnionOf:
     first: typing.List[DataRange]
     def __init__(self, first: typing.List[DataRange]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataUnionOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataUnionOf:
+        ...
     ...
 
 class DataComplementOf:
     first: DataRange
     def __init__(self, first: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataComplementOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataComplementOf:
+        ...
     ...
 
 class DataOneOf:
     first: typing.List[Literal]
     def __init__(self, first: typing.List[Literal]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataOneOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataOneOf:
+        ...
     ...
 
 class DatatypeRestriction:
@@ -162,12 +392,32 @@ class DatatypeRestriction:
     second: typing.List[FacetRestriction]
     def __init__(self, first: Datatype, second: typing.List[FacetRestriction]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DatatypeRestriction:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DatatypeRestriction:
+        ...
     ...
 
 class SimpleLiteral:
     literal: str
     def __init__(self, literal: str):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SimpleLiteral:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SimpleLiteral:
+        ...
     ...
 
 class LanguageLiteral:
@@ -175,6 +425,16 @@ class LanguageLiteral:
     lang: str
     def __init__(self, literal: str, lang: str):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> LanguageLiteral:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> LanguageLiteral:
+        ...
     ...
 
 class DatatypeLiteral:
@@ -182,18 +442,48 @@ class DatatypeLiteral:
     datatype_iri: IRI
     def __init__(self, literal: str, datatype_iri: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DatatypeLiteral:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DatatypeLiteral:
+        ...
     ...
 
 class ObjectProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectProperty:
+        ...
     ...
 
 class InverseObjectProperty:
     first: ObjectProperty
     def __init__(self, first: ObjectProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> InverseObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> InverseObjectProperty:
+        ...
     ...
 
 class AnnotatedAxiom:
@@ -201,6 +491,16 @@ class AnnotatedAxiom:
     ann: typing.Set[Annotation]
     def __init__(self, axiom: Axiom, ann: typing.Set[Annotation]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnnotatedAxiom:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnnotatedAxiom:
+        ...
     ...
 
 class Annotation:
@@ -208,6 +508,16 @@ class Annotation:
     av: AnnotationValue
     def __init__(self, ap: AnnotationProperty, av: AnnotationValue):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> Annotation:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> Annotation:
+        ...
     ...
 
 class AnnotationAssertion:
@@ -215,12 +525,32 @@ class AnnotationAssertion:
     ann: Annotation
     def __init__(self, subject: AnnotationSubject, ann: Annotation):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnnotationAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnnotationAssertion:
+        ...
     ...
 
 class AnnotationProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnnotationProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnnotationProperty:
+        ...
     ...
 
 class AnnotationPropertyDomain:
@@ -228,6 +558,16 @@ class AnnotationPropertyDomain:
     iri: IRI
     def __init__(self, ap: AnnotationProperty, iri: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnnotationPropertyDomain:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnnotationPropertyDomain:
+        ...
     ...
 
 class AnnotationPropertyRange:
@@ -235,18 +575,48 @@ class AnnotationPropertyRange:
     iri: IRI
     def __init__(self, ap: AnnotationProperty, iri: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnnotationPropertyRange:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnnotationPropertyRange:
+        ...
     ...
 
 class AnonymousIndividual:
     first: str
     def __init__(self, first: str):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AnonymousIndividual:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AnonymousIndividual:
+        ...
     ...
 
 class AsymmetricObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> AsymmetricObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> AsymmetricObjectProperty:
+        ...
     ...
 
 class ClassAssertion:
@@ -254,12 +624,32 @@ class ClassAssertion:
     i: Individual
     def __init__(self, ce: ClassExpression, i: Individual):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ClassAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ClassAssertion:
+        ...
     ...
 
 class DataProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataProperty:
+        ...
     ...
 
 class DataPropertyAssertion:
@@ -268,6 +658,16 @@ class DataPropertyAssertion:
     to: Literal
     def __init__(self, dp: DataProperty,  to: Literal):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataPropertyAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataPropertyAssertion:
+        ...
     ...
 
 class DataPropertyDomain:
@@ -275,6 +675,16 @@ class DataPropertyDomain:
     ce: ClassExpression
     def __init__(self, dp: DataProperty, ce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataPropertyDomain:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataPropertyDomain:
+        ...
     ...
 
 class DataPropertyRange:
@@ -282,6 +692,16 @@ class DataPropertyRange:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DataPropertyRange:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DataPropertyRange:
+        ...
     ...
 
 class DatatypeDefinition:
@@ -289,66 +709,176 @@ class DatatypeDefinition:
     range: DataRange
     def __init__(self, kind: Datatype, range: DataRange):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DatatypeDefinition:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DatatypeDefinition:
+        ...
     ...
 
 class DeclareAnnotationProperty:
     first: AnnotationProperty
     def __init__(self, first: AnnotationProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareAnnotationProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareAnnotationProperty:
+        ...
     ...
 
 class DeclareClass:
     first: Class
     def __init__(self, first: Class):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareClass:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareClass:
+        ...
     ...
 
 class DeclareDataProperty:
     first: DataProperty
     def __init__(self, first: DataProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareDataProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareDataProperty:
+        ...
     ...
 
 class DeclareDatatype:
     first: Datatype
     def __init__(self, first: Datatype):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareDatatype:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareDatatype:
+        ...
     ...
 
 class DeclareNamedIndividual:
     first: NamedIndividual
     def __init__(self, first: NamedIndividual):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareNamedIndividual:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareNamedIndividual:
+        ...
     ...
 
 class DeclareObjectProperty:
     first: ObjectProperty
     def __init__(self, first: ObjectProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DeclareObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DeclareObjectProperty:
+        ...
     ...
 
 class DifferentIndividuals:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DifferentIndividuals:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DifferentIndividuals:
+        ...
     ...
 
 class DisjointClasses:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DisjointClasses:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DisjointClasses:
+        ...
     ...
 
 class DisjointDataProperties:
     first: typing.List[DataProperty]
     def __init__(self, first: typing.List[DataProperty]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DisjointDataProperties:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DisjointDataProperties:
+        ...
     ...
 
 class DisjointObjectProperties:
     first: typing.List[ObjectPropertyExpression]
     def __init__(self, first: typing.List[ObjectPropertyExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DisjointObjectProperties:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DisjointObjectProperties:
+        ...
     ...
 
 class DisjointUnion:
@@ -356,24 +886,64 @@ class DisjointUnion:
     second: typing.List[ClassExpression]
     def __init__(self, first: Class, second: typing.List[ClassExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> DisjointUnion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> DisjointUnion:
+        ...
     ...
 
 class EquivalentClasses:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> EquivalentClasses:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> EquivalentClasses:
+        ...
     ...
 
 class EquivalentDataProperties:
     first: typing.List[DataProperty]
     def __init__(self, first: typing.List[DataProperty]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> EquivalentDataProperties:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> EquivalentDataProperties:
+        ...
     ...
 
 class EquivalentObjectProperties:
     first: typing.List[ObjectPropertyExpression]
     def __init__(self, first: typing.List[ObjectPropertyExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> EquivalentObjectProperties:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> EquivalentObjectProperties:
+        ...
     ...
 
 class FacetRestriction:
@@ -381,18 +951,48 @@ class FacetRestriction:
     l: Literal
     def __init__(self, f: Facet, l: Literal):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> FacetRestriction:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> FacetRestriction:
+        ...
     ...
 
 class FunctionalDataProperty:
     first: DataProperty
     def __init__(self, first: DataProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> FunctionalDataProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> FunctionalDataProperty:
+        ...
     ...
 
 class FunctionalObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> FunctionalObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> FunctionalObjectProperty:
+        ...
     ...
 
 class HasKey:
@@ -400,6 +1000,16 @@ class HasKey:
     vpe: typing.List[PropertyExpression]
     def __init__(self, ce: ClassExpression, vpe: typing.List[PropertyExpression]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> HasKey:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> HasKey:
+        ...
     ...
 
 class IRI:
@@ -410,12 +1020,32 @@ class Import:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> Import:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> Import:
+        ...
     ...
 
 class InverseFunctionalObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> InverseFunctionalObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> InverseFunctionalObjectProperty:
+        ...
     ...
 
 class InverseObjectProperties:
@@ -423,18 +1053,48 @@ class InverseObjectProperties:
     second: ObjectProperty
     def __init__(self, first: ObjectProperty, second: ObjectProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> InverseObjectProperties:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> InverseObjectProperties:
+        ...
     ...
 
 class IrreflexiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> IrreflexiveObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> IrreflexiveObjectProperty:
+        ...
     ...
 
 class NamedIndividual:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> NamedIndividual:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> NamedIndividual:
+        ...
     ...
 
 class NegativeDataPropertyAssertion:
@@ -443,6 +1103,16 @@ class NegativeDataPropertyAssertion:
     to: Literal
     def __init__(self, dp: DataProperty,  to: Literal):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> NegativeDataPropertyAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> NegativeDataPropertyAssertion:
+        ...
     ...
 
 class NegativeObjectPropertyAssertion:
@@ -451,6 +1121,16 @@ class NegativeObjectPropertyAssertion:
     to: Individual
     def __init__(self, ope: ObjectPropertyExpression,  to: Individual):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> NegativeObjectPropertyAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> NegativeObjectPropertyAssertion:
+        ...
     ...
 
 class ObjectPropertyAssertion:
@@ -459,6 +1139,16 @@ class ObjectPropertyAssertion:
     to: Individual
     def __init__(self, ope: ObjectPropertyExpression,  to: Individual):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectPropertyAssertion:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectPropertyAssertion:
+        ...
     ...
 
 class ObjectPropertyDomain:
@@ -466,6 +1156,16 @@ class ObjectPropertyDomain:
     ce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, ce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectPropertyDomain:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectPropertyDomain:
+        ...
     ...
 
 class ObjectPropertyRange:
@@ -473,24 +1173,64 @@ class ObjectPropertyRange:
     ce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, ce: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ObjectPropertyRange:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ObjectPropertyRange:
+        ...
     ...
 
 class OntologyAnnotation:
     first: Annotation
     def __init__(self, first: Annotation):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> OntologyAnnotation:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> OntologyAnnotation:
+        ...
     ...
 
 class ReflexiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> ReflexiveObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> ReflexiveObjectProperty:
+        ...
     ...
 
 class SameIndividual:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SameIndividual:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SameIndividual:
+        ...
     ...
 
 class SubAnnotationPropertyOf:
@@ -498,6 +1238,16 @@ class SubAnnotationPropertyOf:
     sub: AnnotationProperty
     def __init__(self, sup: AnnotationProperty, sub: AnnotationProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SubAnnotationPropertyOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SubAnnotationPropertyOf:
+        ...
     ...
 
 class SubClassOf:
@@ -505,6 +1255,16 @@ class SubClassOf:
     sub: ClassExpression
     def __init__(self, sup: ClassExpression, sub: ClassExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SubClassOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SubClassOf:
+        ...
     ...
 
 class SubDataPropertyOf:
@@ -512,6 +1272,16 @@ class SubDataPropertyOf:
     sub: DataProperty
     def __init__(self, sup: DataProperty, sub: DataProperty):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SubDataPropertyOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SubDataPropertyOf:
+        ...
     ...
 
 class SubObjectPropertyOf:
@@ -519,18 +1289,48 @@ class SubObjectPropertyOf:
     sub: SubObjectPropertyExpression
     def __init__(self, sup: ObjectPropertyExpression, sub: SubObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SubObjectPropertyOf:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SubObjectPropertyOf:
+        ...
     ...
 
 class SymmetricObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> SymmetricObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> SymmetricObjectProperty:
+        ...
     ...
 
 class TransitiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_dict(self) -> dict:
+        ...
+    def to_json(self) -> str:
+        ...
+    @classmethod
+    def from_dict(cls, value: dict) -> TransitiveObjectProperty:
+        ...
+    @classmethod
+    def from_json(cls, value: str) -> TransitiveObjectProperty:
+        ...
     ...
 
 class Facet:
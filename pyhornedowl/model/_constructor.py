@@ -0,0 +1,42 @@
+from ..pyhornedowl import Pattern, Var
+
+
+def _is_pattern(value) -> bool:
+    if isinstance(value, (Var, Pattern)):
+        return True
+    if isinstance(value, (list, tuple, set, frozenset)):
+        return any(_is_pattern(v) for v in value)
+    return False
+
+
+class _ConstructorType(type):
+    """Metaclass of the model class constructors exported by `pyhornedowl.model`.
+
+    Calling a constructor with a `Var` or `Pattern` among its arguments returns a `Pattern`
+    of the model class instead of a model object. Everything else is delegated to the model
+    class, so the constructors can be used for `isinstance` checks and attribute access.
+    """
+
+    def __call__(cls, *args, **kwargs):
+        if any(_is_pattern(a) for a in args) or any(_is_pattern(v) for v in kwargs.values()):
+            return Pattern(cls.__model__, *args, **kwargs)
+        return cls.__model__(*args, **kwargs)
+
+    def __instancecheck__(cls, instance):
+        return isinstance(instance, cls.__model__)
+
+    def __subclasscheck__(cls, subclass):
+        return issubclass(subclass, cls.__model__)
+
+    def __getattr__(cls, name):
+        return getattr(cls.__model__, name)
+
+
+def constructor(model_class: type) -> type:
+    """Returns the constructor of a model class which builds a `Pattern` if given variables."""
+    return _ConstructorType(model_class.__name__, (), {
+        "__model__": model_class,
+        "__module__": model_class.__module__,
+        "__qualname__": model_class.__qualname__,
+        "__doc__": model_class.__doc__,
+    })
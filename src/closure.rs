@@ -0,0 +1,172 @@
+use horned_owl::model::{ArcStr, IRI};
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Maps an IRI to the IRIs directly related to it, e.g. a class to its direct subclasses.
+pub type Index = HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>;
+
+/// Maps an IRI to an `Index` per property, e.g. a class to the classes it is related to by
+/// each object property.
+pub type RelationIndex = HashMap<IRI<ArcStr>, Index>;
+
+/// Records that `to` is directly related to `from`.
+pub fn link(index: &mut Index, from: IRI<ArcStr>, to: IRI<ArcStr>) {
+    index.entry(from).or_insert(HashSet::new()).insert(to);
+}
+
+/// Removes the direct relation between `from` and `to`.
+pub fn unlink(index: &mut Index, from: &IRI<ArcStr>, to: &IRI<ArcStr>) {
+    if let Some(related) = index.get_mut(from) {
+        related.remove(to);
+    }
+}
+
+/// Returns the IRIs directly related to `iri` as strings.
+pub fn related(index: &Index, iri: &IRI<ArcStr>) -> HashSet<String> {
+    index
+        .get(iri)
+        .map(|related| related.iter().map(|r| r.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// The result of a transitive traversal over an `Index`.
+pub struct Closure {
+    /// All IRIs reached from the start.
+    pub members: HashSet<IRI<ArcStr>>,
+    /// Cycles found between the reached IRIs, each given as the IRIs along the cycle.
+    pub cycles: Vec<Vec<IRI<ArcStr>>>,
+}
+
+/// Computes the transitive closure of `start` over `index`.
+///
+/// The traversal is iterative and visits every IRI once, so neither cycles nor deep
+/// hierarchies can overflow the stack. If `max_depth` is given, only IRIs at most that many
+/// steps away from `start` are included.
+pub fn closure(
+    index: &Index,
+    start: &IRI<ArcStr>,
+    include_self: bool,
+    max_depth: Option<usize>,
+) -> Closure {
+    let mut seen: HashSet<IRI<ArcStr>> = HashSet::new();
+    let mut queue: VecDeque<(IRI<ArcStr>, usize)> = VecDeque::new();
+
+    seen.insert(start.clone());
+    queue.push_back((start.clone(), 0));
+
+    while let Some((iri, depth)) = queue.pop_front() {
+        if max_depth.map_or(false, |max_depth| depth >= max_depth) {
+            continue;
+        }
+
+        if let Some(next) = index.get(&iri) {
+            for n in next {
+                if seen.insert(n.clone()) {
+                    queue.push_back((n.clone(), depth + 1));
+                }
+            }
+        }
+    }
+
+    let cycles = find_cycles(index, start, &seen);
+
+    if !include_self {
+        seen.remove(start);
+    }
+
+    Closure {
+        members: seen,
+        cycles,
+    }
+}
+
+/// Computes the IRIs related to `start` by a path of `property` edges in `relations`.
+///
+/// If `superclasses` is given, its edges may occur anywhere along the path, but paths made
+/// only of these edges do not relate `start` to anything.
+pub fn relation_closure(
+    relations: &RelationIndex,
+    property: &IRI<ArcStr>,
+    start: &IRI<ArcStr>,
+    superclasses: Option<&Index>,
+) -> HashSet<IRI<ArcStr>> {
+    //Each IRI is visited at most twice, once before and once after a property edge was taken
+    let mut seen: HashSet<(IRI<ArcStr>, bool)> = HashSet::new();
+    let mut queue: VecDeque<(IRI<ArcStr>, bool)> = VecDeque::new();
+    seen.insert((start.clone(), false));
+    queue.push_back((start.clone(), false));
+
+    while let Some((iri, related)) = queue.pop_front() {
+        let by_property = relations
+            .get(&iri)
+            .and_then(|r| r.get(property))
+            .into_iter()
+            .flatten()
+            .map(|n| (n, true));
+        let by_subclass = superclasses
+            .and_then(|s| s.get(&iri))
+            .into_iter()
+            .flatten()
+            .map(|n| (n, related));
+
+        for (n, related) in by_property.chain(by_subclass) {
+            if seen.insert((n.clone(), related)) {
+                queue.push_back((n.clone(), related));
+            }
+        }
+    }
+
+    seen.into_iter()
+        .filter(|(_, related)| *related)
+        .map(|(iri, _)| iri)
+        .collect()
+}
+
+/// Finds the cycles between the IRIs of `region` that are reachable from `start`.
+fn find_cycles(
+    index: &Index,
+    start: &IRI<ArcStr>,
+    region: &HashSet<IRI<ArcStr>>,
+) -> Vec<Vec<IRI<ArcStr>>> {
+    let successors = |iri: &IRI<ArcStr>| -> Vec<IRI<ArcStr>> {
+        index
+            .get(iri)
+            .map(|next| next.iter().filter(|n| region.contains(*n)).cloned().collect())
+            .unwrap_or_default()
+    };
+
+    let mut cycles = Vec::new();
+    let mut on_path: HashSet<IRI<ArcStr>> = HashSet::new();
+    let mut done: HashSet<IRI<ArcStr>> = HashSet::new();
+
+    //Depth first search with an explicit stack of IRIs and their unvisited successors
+    let mut stack: Vec<(IRI<ArcStr>, Vec<IRI<ArcStr>>)> = vec![(start.clone(), successors(start))];
+    on_path.insert(start.clone());
+
+    loop {
+        let next = match stack.last_mut() {
+            Some((_, remaining)) => remaining.pop(),
+            None => break,
+        };
+
+        match next {
+            Some(n) => {
+                if on_path.contains(&n) {
+                    let pos = stack.iter().position(|(iri, _)| iri == &n).unwrap();
+                    cycles.push(stack[pos..].iter().map(|(iri, _)| iri.clone()).collect());
+                } else if !done.contains(&n) {
+                    on_path.insert(n.clone());
+                    let next_successors = successors(&n);
+                    stack.push((n, next_successors));
+                }
+            }
+            None => {
+                let (iri, _) = stack.pop().unwrap();
+                on_path.remove(&iri);
+                done.insert(iri);
+            }
+        }
+    }
+
+    cycles
+}
@@ -1,13 +1,20 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyRuntimeError, PyRuntimeWarning, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{IntoPyDict, PyBytes, PyDict, PyString, PyTuple, PyType};
 use pyo3::wrap_pyfunction;
 use std::fs::File;
 use std::io::BufReader;
 
 #[macro_use]
 mod doc;
+mod closure;
 mod model;
+mod obographs;
+mod query;
+mod rdf;
+mod sparql;
+
+use model::{MapIRIs, ToTerm, WalkEntities};
 
 use horned_owl::model::*;
 use horned_owl::ontology::axiom_mapped::AxiomMappedOntology;
@@ -32,25 +39,95 @@ use std::collections::{BTreeSet, HashMap};
 use std::default::Default;
 use std::ops::Deref;
 use std::path::Path;
-use std::time::Instant;
 //use failure::Error;
 use std::sync::Arc;
 
+/// A value for an annotation assertion, either a model value or a plain string.
+#[derive(FromPyObject)]
+enum AnnotationValueArg {
+    Value(model::AnnotationValue),
+    String(String),
+}
+
+/// An axiom with or without its annotations.
+#[derive(FromPyObject)]
+enum AxiomArg {
+    Annotated(model::AnnotatedAxiom),
+    Axiom(model::Axiom),
+}
+
+/// Determines which axioms build the class hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HierarchyMode {
+    /// Only `SubClassOf` axioms between named classes.
+    Asserted,
+    /// Additionally named equivalents and the named conjuncts of intersections.
+    Equivalents,
+}
+
+/// Iterates lazily over the axioms of an ontology, or of one of its entities, converting them to
+/// `model.AnnotatedAxiom` one at a time.
+#[pyclass(module = "pyhornedowl")]
+struct AxiomIterator {
+    ontology: Py<PyIndexedOntology>,
+    //Revision of the ontology when the iterator was created
+    revision: usize,
+    //The axioms when the iterator was created, shared with the ontology
+    axioms: std::vec::IntoIter<Arc<AnnotatedAxiom<ArcStr>>>,
+}
+
+#[pymethods]
+impl AxiomIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let ontology = self.ontology.borrow(py);
+        if ontology.revision != self.revision {
+            return Err(PyRuntimeError::new_err("Ontology changed during iteration"));
+        }
+
+        Ok(self
+            .axioms
+            .next()
+            .map(|aax| model::AnnotatedAxiom::from(&*aax).into_py(py)))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.axioms.len()
+    }
+}
 
 /// Represents a loaded ontology.
-#[pyclass]
+#[pyclass(module = "pyhornedowl")]
 struct PyIndexedOntology {
     //State variables private to Rust, exposed through methods to Python
     labels_to_iris: HashMap<String, IRI<ArcStr>>,
 
     classes_to_subclasses: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>, //axiom typed index would give subclass axioms
     classes_to_superclasses: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>,
+    //Which axioms contribute to the class hierarchy indexes
+    hierarchy_mode: HierarchyMode,
+
+    properties_to_subproperties: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>,
+    properties_to_superproperties: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>,
+    properties_to_inverses: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>,
+    properties_to_chains: HashMap<IRI<ArcStr>, HashSet<Vec<IRI<ArcStr>>>>,
+
+    //Existential restrictions between named classes, by class and object property
+    classes_to_relations: HashMap<IRI<ArcStr>, HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>>,
 
     //The primary store of the axioms is a Horned OWL indexed ontology
     ontology: ArcIRIMappedOntology,
     //Need this for converting IRIs to IDs and for saving again afterwards
     mapping: PrefixMapping,
     build: Build<ArcStr>,
+
+    //The RDF view queried by `sparql`, built on demand and dropped when the ontology changes
+    rdf_graph: Option<rdf::Graph>,
+    //Incremented whenever axioms are added or removed, so that iterators notice the change
+    revision: usize,
 }
 
 impl Default for PyIndexedOntology {
@@ -59,15 +136,49 @@ impl Default for PyIndexedOntology {
             labels_to_iris: Default::default(),
             classes_to_subclasses: Default::default(),
             classes_to_superclasses: Default::default(),
+            hierarchy_mode: HierarchyMode::Asserted,
+            properties_to_subproperties: Default::default(),
+            properties_to_superproperties: Default::default(),
+            properties_to_inverses: Default::default(),
+            properties_to_chains: Default::default(),
+            classes_to_relations: Default::default(),
             ontology: ArcIRIMappedOntology::new_arc(),
             mapping: Default::default(),
             build: Build::new_arc(),
+            rdf_graph: None,
+            revision: 0,
         }
     }
 }
 
 #[pymethods]
 impl PyIndexedOntology {
+    /// __init__(self) -> None
+    /// 
+    /// Creates an empty ontology.
+    #[new]
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<(PyObject, String)> {
+        Ok((PyBytes::new(py, &self.to_owx()?).into_py(py), self.get_hierarchy_mode()))
+    }
+
+    fn __setstate__(&mut self, state: (&[u8], String)) -> PyResult<()> {
+        let (owx, mode) = state;
+        *self = PyIndexedOntology::from_owx(owx, HierarchyMode::Asserted)?;
+        self.set_hierarchy_mode(mode)
+    }
+
+    fn __copy__(&self) -> PyResult<Self> {
+        PyIndexedOntology::from_owx(&self.to_owx()?, self.hierarchy_mode)
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> PyResult<Self> {
+        self.__copy__()
+    }
+
     /// get_id_for_iri(self, iri: str) -> Optional[str]
     /// 
     /// Gets the ID of term by it IRI.
@@ -125,57 +236,106 @@ impl PyIndexedOntology {
     /// 
     /// Sets the label of a term by iri.
     /// 
-    /// Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`.
+    /// Adds or replaces the `AnnotationAssertion` axioms for `rdfs:label`.
     fn set_label(&mut self, iri: String, label: String) -> PyResult<()> {
-        let iri = self.build.iri(iri);
+        self.set_annotation(
+            iri,
+            AnnotationBuiltIn::LABEL.iri_s().clone(),
+            AnnotationValueArg::String(label),
+            None,
+            None,
+            true,
+        )
+    }
 
-        let ax1: AnnotatedAxiom<ArcStr> = Axiom::AnnotationAssertion(AnnotationAssertion {
-            subject: iri.clone().into(),
-            ann: Annotation {
-                ap: self
-                    .build
-                    .annotation_property(AnnotationBuiltIn::LABEL.iri_s().clone()),
-                av: AnnotationValue::Literal(Literal::Simple {
-                    literal: label.clone(),
-                }),
-            },
-        })
-        .into();
+    /// set_annotation(self, iri: str, property: str, value: Union[str, model.IRI, model.Literal], lang: Optional[str] = None, datatype: Optional[str] = None, replace: bool = True) -> None
+    /// 
+    /// Sets the value of an annotation property for a term by iri.
+    /// 
+    /// A string `value` is stored as a simple literal, as a language tagged literal if `lang` is given
+    /// or as a typed literal if `datatype` is given. `model.IRI` and `model.Literal` values are stored as they are.
+    /// If `replace` is true, the existing values of the annotation property in the same language are removed first:
+    /// values with the same language tag for a language tagged literal and values without a language tag otherwise.
+    #[pyo3(signature = (iri, property, value, lang=None, datatype=None, replace=true))]
+    fn set_annotation(
+        &mut self,
+        iri: String,
+        property: String,
+        value: AnnotationValueArg,
+        lang: Option<String>,
+        datatype: Option<String>,
+        replace: bool,
+    ) -> PyResult<()> {
+        let av = self.annotation_value(value, lang, datatype)?;
 
-        //If we already have a label, update it:
-        let old_ax = &self
-            .ontology
-            .axiom_for_iri(&iri)
-            .filter_map(|aax: &AnnotatedAxiom<ArcStr>| match &aax.axiom {
-                Axiom::AnnotationAssertion(AnnotationAssertion {
-                    subject: _subj,
-                    ann,
-                }) => match ann {
-                    Annotation {
-                        ap,
-                        av: AnnotationValue::Literal(Literal::Simple { literal: _old }),
-                    } => {
-                        if AnnotationBuiltIn::LABEL.iri_s().eq(&ap.0.to_string()) {
-                            Some(aax.clone())
-                        } else {
-                            None
+        let iri = self.build.iri(iri);
+        let ap = self.build.annotation_property(property);
+
+        if replace {
+            let lang = language_of(&av);
+            let old_axioms: Vec<AnnotatedAxiom<ArcStr>> = self
+                .annotation_assertions(&iri, &ap)
+                .into_iter()
+                .filter(|aax| match &aax.axiom {
+                    Axiom::AnnotationAssertion(AnnotationAssertion { ann, .. }) => {
+                        match (language_of(&ann.av), lang) {
+                            (Some(old), Some(new)) => old.eq_ignore_ascii_case(new),
+                            (old, new) => old.is_none() && new.is_none(),
                         }
                     }
-                    _ => None,
-                },
-                _ => None,
-            })
-            .next();
+                    _ => false,
+                })
+                .collect();
 
-        if let Some(old_ax) = old_ax {
-            self.ontology.update_axiom(old_ax, ax1);
-        } else {
-            //If no label already, just add one
-            self.ontology.insert(ax1);
+            for old_ax in old_axioms.iter() {
+                self.remove_annotated_axiom(old_ax);
+            }
         }
+
+        let ax: AnnotatedAxiom<ArcStr> = Axiom::AnnotationAssertion(AnnotationAssertion {
+            subject: iri.into(),
+            ann: Annotation { ap, av },
+        })
+        .into();
+
+        self.add_annotated_axiom(ax);
         Ok(())
     }
 
+    /// add_annotation(self, iri: str, property: str, value: Union[str, model.IRI, model.Literal], lang: Optional[str] = None, datatype: Optional[str] = None) -> None
+    /// 
+    /// Adds a value for an annotation property to a term by iri.
+    /// 
+    /// Existing values of the annotation property are kept. See `PyIndexedOntology.set_annotation` for the handling of `value`.
+    #[pyo3(signature = (iri, property, value, lang=None, datatype=None))]
+    fn add_annotation(
+        &mut self,
+        iri: String,
+        property: String,
+        value: AnnotationValueArg,
+        lang: Option<String>,
+        datatype: Option<String>,
+    ) -> PyResult<()> {
+        self.set_annotation(iri, property, value, lang, datatype, false)
+    }
+
+    /// remove_annotations(self, iri: str, property: str) -> int
+    /// 
+    /// Removes all values of an annotation property from a term by iri.
+    /// 
+    /// Returns the number of removed `AnnotationAssertion` axioms.
+    fn remove_annotations(&mut self, iri: String, property: String) -> PyResult<usize> {
+        let iri = self.build.iri(iri);
+        let ap = self.build.annotation_property(property);
+        let old_axioms = self.annotation_assertions(&iri, &ap);
+
+        for old_ax in old_axioms.iter() {
+            self.remove_annotated_axiom(old_ax);
+        }
+
+        Ok(old_axioms.len())
+    }
+
     /// get_iri_for_label(self, label: str) -> Optional[str]
     /// 
     /// Returns the IRI of a term by its label if it exists.
@@ -214,6 +374,67 @@ impl PyIndexedOntology {
         }
     }
 
+    /// set_iri(self, iri: Optional[str]) -> None
+    /// 
+    /// Sets the ontology iri. If `iri` is `None`, the ontology iri is removed.
+    fn set_iri(&mut self, iri: Option<String>) -> PyResult<()> {
+        let iri = iri.map(|iri| self.build.iri(iri));
+        self.ontology.mut_id().iri = iri;
+        self.rdf_graph = None;
+        Ok(())
+    }
+
+    /// set_version_iri(self, iri: Optional[str]) -> None
+    /// 
+    /// Sets the ontologys version iri. If `iri` is `None`, the version iri is removed.
+    fn set_version_iri(&mut self, iri: Option<String>) -> PyResult<()> {
+        let iri = iri.map(|iri| self.build.iri(iri));
+        self.ontology.mut_id().viri = iri;
+        self.rdf_graph = None;
+        Ok(())
+    }
+
+    /// get_ontology_annotations(self) -> List[model.Annotation]
+    /// 
+    /// Returns the annotations of the ontology itself, e.g. title, license or description.
+    fn get_ontology_annotations(&mut self) -> PyResult<Vec<model::Annotation>> {
+        let annotations = self
+            .ontology
+            .axiom_for_kind(AxiomKind::OntologyAnnotation)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::OntologyAnnotation(OntologyAnnotation(ann)) => {
+                    Some(model::Annotation::from(ann))
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(annotations)
+    }
+
+    /// set_ontology_annotations(self, annotations: List[model.Annotation]) -> None
+    /// 
+    /// Replaces all annotations of the ontology itself.
+    fn set_ontology_annotations(&mut self, annotations: Vec<model::Annotation>) -> PyResult<()> {
+        let old_axioms: Vec<AnnotatedAxiom<ArcStr>> = self
+            .ontology
+            .axiom_for_kind(AxiomKind::OntologyAnnotation)
+            .cloned()
+            .collect();
+
+        for old_ax in old_axioms.iter() {
+            self.remove_annotated_axiom(old_ax);
+        }
+
+        for ann in annotations {
+            self.add_annotated_axiom(
+                Axiom::OntologyAnnotation(OntologyAnnotation(ann.into())).into(),
+            );
+        }
+
+        Ok(())
+    }
+
     /// get_subclasses(self, iri: str) -> Set[str]
     /// 
     /// Gets all subclasses of an entity.
@@ -244,6 +465,306 @@ impl PyIndexedOntology {
         }
     }
 
+    /// set_hierarchy_mode(self, mode: str) -> None
+    /// 
+    /// Sets which axioms build the class hierarchy and rebuilds it.
+    /// 
+    /// In the default mode `"asserted"` only `SubClassOf` axioms between named classes are used. In the mode
+    /// `"equivalents"`, named classes in `EquivalentClasses` axioms are additionally parents of each other and
+    /// the named conjuncts of an `ObjectIntersectionOf` in `EquivalentClasses` or `SubClassOf` axioms are parents as well.
+    fn set_hierarchy_mode(&mut self, mode: String) -> PyResult<()> {
+        self.hierarchy_mode = match mode.as_str() {
+            "asserted" => HierarchyMode::Asserted,
+            "equivalents" => HierarchyMode::Equivalents,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown hierarchy mode '{}'. Expected 'asserted' or 'equivalents'.",
+                    mode
+                )))
+            }
+        };
+        self.rebuild_hierarchy();
+
+        Ok(())
+    }
+
+    /// get_hierarchy_mode(self) -> str
+    /// 
+    /// Returns the mode used to build the class hierarchy, either `"asserted"` or `"equivalents"`.
+    fn get_hierarchy_mode(&self) -> String {
+        match self.hierarchy_mode {
+            HierarchyMode::Asserted => "asserted",
+            HierarchyMode::Equivalents => "equivalents",
+        }
+        .to_string()
+    }
+
+    /// get_sub_properties(self, iri: str) -> Set[str]
+    /// 
+    /// Gets the direct sub properties of an object, data or annotation property.
+    fn get_sub_properties(&mut self, iri: String) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(iri);
+
+        Ok(closure::related(&self.properties_to_subproperties, &iri))
+    }
+
+    /// get_super_properties(self, iri: str) -> Set[str]
+    /// 
+    /// Gets the direct super properties of an object, data or annotation property.
+    fn get_super_properties(&mut self, iri: String) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(iri);
+
+        Ok(closure::related(&self.properties_to_superproperties, &iri))
+    }
+
+    /// get_inverse(self, iri: str) -> Set[str]
+    /// 
+    /// Gets the object properties declared inverse of an object property by `InverseObjectProperties` axioms.
+    fn get_inverse(&mut self, iri: String) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(iri);
+
+        Ok(closure::related(&self.properties_to_inverses, &iri))
+    }
+
+    /// get_property_chains(self, iri: str) -> List[List[str]]
+    /// 
+    /// Gets the property chains that imply an object property.
+    /// 
+    /// Chains containing inverse object properties are not included.
+    fn get_property_chains(&mut self, iri: String) -> PyResult<Vec<Vec<String>>> {
+        let iri = self.build.iri(iri);
+
+        let chains = self
+            .properties_to_chains
+            .get(&iri)
+            .map(|chains| {
+                chains
+                    .iter()
+                    .map(|chain| chain.iter().map(|op| op.to_string()).collect())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(chains)
+    }
+
+    /// to_edge_list(self, relations: Optional[List[str]] = None, use_labels: bool = False) -> List[Tuple[str, str, str]]
+    /// 
+    /// Gets the edges between named classes as `(source, predicate, target)` tuples.
+    /// 
+    /// The predicate `"subClassOf"` gives the edges of the class hierarchy as returned by `get_superclasses`,
+    /// so they depend on the hierarchy mode. Other predicates are object properties relating the classes as
+    /// returned by `get_related`, which includes relations from conjuncts of intersections and from equivalent
+    /// class expressions. If `relations` is given,
+    /// only edges with these predicates are returned. With `use_labels`, classes and properties are given by their
+    /// label if they have one.
+    #[pyo3(signature = (relations=None, use_labels=false))]
+    fn to_edge_list(
+        &mut self,
+        relations: Option<Vec<String>>,
+        use_labels: bool,
+    ) -> PyResult<Vec<(String, String, String)>> {
+        let included = |predicate: &str| {
+            relations
+                .as_ref()
+                .map_or(true, |r| r.iter().any(|p| p == predicate))
+        };
+
+        let mut edges: BTreeSet<(String, String, String)> = BTreeSet::new();
+        if included(SUBCLASS_OF) {
+            for (sub, sups) in &self.classes_to_superclasses {
+                for sup in sups {
+                    edges.insert((sub.to_string(), SUBCLASS_OF.to_string(), sup.to_string()));
+                }
+            }
+        }
+        for (sub, relations) in &self.classes_to_relations {
+            for (p, fillers) in relations {
+                if included(&p.to_string()) {
+                    for filler in fillers {
+                        edges.insert((sub.to_string(), p.to_string(), filler.to_string()));
+                    }
+                }
+            }
+        }
+
+        if !use_labels {
+            return Ok(edges.into_iter().collect());
+        }
+
+        let mut labels: HashMap<String, String> = HashMap::new();
+        let mut label = |iri: String| -> String {
+            labels
+                .entry(iri.clone())
+                .or_insert_with(|| self.label_of(&self.build.iri(iri.clone())).unwrap_or(iri))
+                .clone()
+        };
+
+        Ok(edges
+            .into_iter()
+            .map(|(sub, p, sup)| {
+                let p = if p == SUBCLASS_OF { p } else { label(p) };
+                (label(sub), p, label(sup))
+            })
+            .collect())
+    }
+
+    /// to_networkx(self, relations: Optional[List[str]] = None, use_labels: bool = False, multi: bool = False) -> typing.Any
+    /// 
+    /// Builds a `networkx.DiGraph` from the edges returned by `to_edge_list`.
+    /// 
+    /// The predicate of an edge is stored in its attribute `predicate`. A `DiGraph` keeps a single edge between
+    /// two classes, so with `multi` a `networkx.MultiDiGraph` keyed by predicate is built instead.
+    #[pyo3(signature = (relations=None, use_labels=false, multi=false))]
+    fn to_networkx(
+        &mut self,
+        py: Python,
+        relations: Option<Vec<String>>,
+        use_labels: bool,
+        multi: bool,
+    ) -> PyResult<PyObject> {
+        let nx = py.import("networkx")?;
+        let graph = nx.call_method0(if multi { "MultiDiGraph" } else { "DiGraph" })?;
+
+        for (source, predicate, target) in self.to_edge_list(relations, use_labels)? {
+            if multi {
+                let attrs = [("predicate", predicate.clone())].into_py_dict(py);
+                graph.call_method("add_edge", (source, target, predicate), Some(attrs))?;
+            } else {
+                let attrs = [("predicate", predicate)].into_py_dict(py);
+                graph.call_method("add_edge", (source, target), Some(attrs))?;
+            }
+        }
+
+        Ok(graph.into_py(py))
+    }
+
+    /// to_table(self, columns: List[str] = ["iri", "curie", "label", "definition", "parents"], path: Optional[str] = None) -> List[Dict[str, str]]
+    /// 
+    /// Gets a row for each declared class, ordered by IRI, with the values of `columns`.
+    /// 
+    /// The columns `"iri"`, `"curie"`, `"label"` and `"definition"` hold the IRI, CURIE, label and definition of the
+    /// class, `"parents"` its direct superclasses by label or CURIE. Any other column holds the values of the
+    /// annotation property with that IRI or CURIE, or the declared annotation property with that label. Several values
+    /// of a cell are separated by `|`.
+    /// 
+    /// If `path` is given, the table is written to that file as well, tab separated if it ends in `.tsv` and comma
+    /// separated otherwise.
+    #[pyo3(signature = (columns=TABLE_COLUMNS.iter().map(|c| c.to_string()).collect(), path=None))]
+    fn to_table(
+        &mut self,
+        py: Python,
+        columns: Vec<String>,
+        path: Option<String>,
+    ) -> PyResult<Vec<PyObject>> {
+        let properties: HashMap<String, IRI<ArcStr>> = columns
+            .iter()
+            .filter(|c| !["iri", "curie", "label", "parents"].contains(&c.as_str()))
+            .map(|c| Ok((c.clone(), self.column_property(c)?)))
+            .collect::<PyResult<_>>()?;
+
+        let classes: BTreeSet<String> = self.get_classes()?.into_iter().collect();
+        let table: Vec<Vec<String>> = classes
+            .into_iter()
+            .map(|class| {
+                let class: IRI<ArcStr> = self.build.iri(class);
+                columns
+                    .iter()
+                    .map(|c| self.table_cell(&class, c, &properties))
+                    .collect()
+            })
+            .collect::<PyResult<_>>()?;
+
+        if let Some(path) = path {
+            let delimiter = if path.ends_with(".tsv") { '\t' } else { ',' };
+            let mut content = String::new();
+            for row in std::iter::once(&columns).chain(&table) {
+                let fields: Vec<String> = row.iter().map(|f| table_field(f, delimiter)).collect();
+                content.push_str(&fields.join(&delimiter.to_string()));
+                content.push('\n');
+            }
+            std::fs::write(path, content)?;
+        }
+
+        table
+            .into_iter()
+            .map(|row| {
+                let dict = PyDict::new(py);
+                for (c, value) in columns.iter().zip(row) {
+                    dict.set_item(c, value)?;
+                }
+                Ok(dict.into_py(py))
+            })
+            .collect()
+    }
+
+    /// get_related(self, iri: str, property: str) -> Set[str]
+    /// 
+    /// Gets the classes directly related to a class by an object property.
+    /// 
+    /// A class `A` is related to `B` by `R` if `A` is a subclass of `ObjectSomeValuesFrom(R, B)`, either
+    /// directly or as a conjunct of an `ObjectIntersectionOf`, or if `A` is equivalent to such a class expression.
+    fn get_related(&mut self, iri: String, property: String) -> PyResult<HashSet<String>> {
+        let iri: IRI<ArcStr> = self.build.iri(iri);
+        let property: IRI<ArcStr> = self.build.iri(property);
+
+        Ok(self
+            .classes_to_relations
+            .get(&iri)
+            .map(|relations| closure::related(relations, &property))
+            .unwrap_or_default())
+    }
+
+    /// get_relations(self, iri: str) -> Dict[str, Set[str]]
+    /// 
+    /// Gets the classes directly related to a class by each object property, as described for `get_related`.
+    fn get_relations(&mut self, iri: String) -> PyResult<HashMap<String, HashSet<String>>> {
+        let iri: IRI<ArcStr> = self.build.iri(iri);
+
+        Ok(self
+            .classes_to_relations
+            .get(&iri)
+            .map(|relations| {
+                relations
+                    .iter()
+                    .filter(|(_, fillers)| !fillers.is_empty())
+                    .map(|(p, _)| (p.to_string(), closure::related(relations, p)))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// get_related_transitive(self, iri: str, property: str, include_is_a: bool = True) -> Set[str]
+    /// 
+    /// Gets the classes related to a class by a chain of `property` edges, treating the property as transitive.
+    /// 
+    /// With `include_is_a`, subclass edges may occur anywhere along the chain, so a class inherits the relations
+    /// of its superclasses and is also related to the superclasses of its related classes. The subclass edges are
+    /// those of `get_superclasses`, so with the hierarchy mode `"equivalents"` they include edges derived from
+    /// equivalent classes and intersections.
+    #[pyo3(signature = (iri, property, include_is_a=true))]
+    fn get_related_transitive(
+        &mut self,
+        iri: String,
+        property: String,
+        include_is_a: bool,
+    ) -> PyResult<HashSet<String>> {
+        let iri: IRI<ArcStr> = self.build.iri(iri);
+        let property: IRI<ArcStr> = self.build.iri(property);
+        let superclasses = if include_is_a {
+            Some(&self.classes_to_superclasses)
+        } else {
+            None
+        };
+
+        Ok(
+            closure::relation_closure(&self.classes_to_relations, &property, &iri, superclasses)
+                .iter()
+                .map(|r| r.to_string())
+                .collect(),
+        )
+    }
+
     /// get_classes(self) -> Set[str]
     /// 
     /// Returns the IRIs of all declared classes in the ontology.
@@ -278,57 +799,319 @@ impl PyIndexedOntology {
         Ok(object_properties)
     }
 
-    /// get_annotation(self, class_iri: str, ann_iri: str) -> Optional[str]
+    /// get_individuals(self) -> Set[str]
     /// 
-    /// Gets the first annotated value for an entity and annotation property.
+    /// Returns the IRIs of all named individuals in the ontology.
     /// 
-    /// Note: If there are multiple annotation axioms for the queried entity and annotation property,
-    /// the order is neither necessarily the same as in the ontology neither is it stable.
-    /// Get all annotation values with `PyIndexedOntology.get_annotations`.
-    fn get_annotation(
-        &mut self,
-        py: Python,
-        class_iri: String,
-        ann_iri: String,
-    ) -> PyResult<PyObject> {
-        let annots = self.get_annotations(class_iri, ann_iri);
-
-        let mut literal_value = ().to_object(py);
+    /// Besides declared individuals, this includes named individuals used in class or property assertions.
+    fn get_individuals(&mut self) -> PyResult<HashSet<String>> {
+        let mut individuals: HashSet<String> = HashSet::new();
+
+        for kind in [
+            AxiomKind::DeclareNamedIndividual,
+            AxiomKind::ClassAssertion,
+            AxiomKind::ObjectPropertyAssertion,
+            AxiomKind::DataPropertyAssertion,
+        ] {
+            for aax in self.ontology.axiom_for_kind(kind) {
+                let named: Vec<&Individual<ArcStr>> = match &aax.axiom {
+                    Axiom::DeclareNamedIndividual(DeclareNamedIndividual(ni)) => {
+                        individuals.insert(ni.0.to_string());
+                        vec![]
+                    }
+                    Axiom::ClassAssertion(ClassAssertion { i, .. }) => vec![i],
+                    Axiom::ObjectPropertyAssertion(ObjectPropertyAssertion { from, to, .. }) => {
+                        vec![from, to]
+                    }
+                    Axiom::DataPropertyAssertion(DataPropertyAssertion { from, .. }) => vec![from],
+                    _ => vec![],
+                };
 
-        if let Ok(literal_values) = annots {
-            if !literal_values.is_empty() {
-                literal_value = literal_values.into_iter().next().to_object(py);
+                for i in named {
+                    if let Individual::Named(ni) = i {
+                        individuals.insert(ni.0.to_string());
+                    }
+                }
             }
         }
 
-        Ok(literal_value)
+        Ok(individuals)
     }
 
-    /// get_annotations(self, class_iri: str, ann_iri: str) -> List[str]
+    /// get_types(self, individual: str) -> Set[str]
     /// 
-    /// Gets all annotated value for an entity and annotation property.
+    /// Gets the named classes an individual is asserted to be an instance of.
+    fn get_types(&mut self, individual: String) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(individual);
+
+        let types = self
+            .ontology
+            .axiom_for_iri(&iri)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::ClassAssertion(ClassAssertion {
+                    ce: ClassExpression::Class(c),
+                    i: Individual::Named(i),
+                }) if i.0 == iri => Some(c.0.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        Ok(types)
+    }
+
+    /// get_instances(self, iri: str, direct: bool = True) -> Set[str]
     /// 
-    /// Note: The order is neither necessarily the same as in the ontology neither is it stable.
-    /// Get all annotation values with `PyIndexedOntology.get_annotations`.
-    fn get_annotations(&mut self, class_iri: String, ann_iri: String) -> PyResult<Vec<String>> {
-        let iri = self.build.iri(class_iri);
+    /// Gets the named individuals asserted to be instances of a class.
+    /// 
+    /// If `direct` is false, the instances of all subclasses of the class are included as well.
+    #[pyo3(signature = (iri, direct=true))]
+    fn get_instances(&mut self, iri: String, direct: bool) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(iri);
 
-        let literal_values : Vec<String> = self.ontology.axiom_for_iri(&iri)
-                                .filter_map(|aax: &AnnotatedAxiom<ArcStr>| {
-            match &aax.axiom {
-                Axiom::AnnotationAssertion(AnnotationAssertion{subject:_,ann}) => {
-                        match ann {
-                            Annotation {ap, av:  AnnotationValue::Literal(Literal::Simple{literal}) } => {
-                                if ann_iri.eq(&ap.0.to_string()) {
-                                    Some(literal.clone())
-                                } else {
-                                    None
-                                }
-                            },
-                            //Language { literal: String, lang: String },
-                            Annotation {ap, av:  AnnotationValue::Literal(Literal::Language{literal, lang:_}) } => {
-                                if ann_iri.eq(&ap.0.to_string()) {
-                                    Some(literal.clone())
+        if direct {
+            return Ok(self.direct_instances(&iri));
+        }
+
+        let classes = closure::closure(&self.classes_to_subclasses, &iri, true, None);
+
+        Ok(classes
+            .members
+            .iter()
+            .flat_map(|c| self.direct_instances(c))
+            .collect())
+    }
+
+    /// get_object_property_values(self, individual: str, property: str) -> Set[str]
+    /// 
+    /// Gets the named individuals an individual is connected to by an object property.
+    /// 
+    /// Assertions using the inverse of the object property are taken into account.
+    fn get_object_property_values(
+        &mut self,
+        individual: String,
+        property: String,
+    ) -> PyResult<HashSet<String>> {
+        let iri = self.build.iri(individual);
+        let op = self.build.object_property(property);
+
+        let values = self
+            .ontology
+            .axiom_for_iri(&iri)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                    ope,
+                    from: Individual::Named(from),
+                    to: Individual::Named(to),
+                }) => match ope {
+                    ObjectPropertyExpression::ObjectProperty(p) if p == &op && from.0 == iri => {
+                        Some(to.0.to_string())
+                    }
+                    ObjectPropertyExpression::InverseObjectProperty(p)
+                        if p == &op && to.0 == iri =>
+                    {
+                        Some(from.0.to_string())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        Ok(values)
+    }
+
+    /// get_data_property_values(self, individual: str, property: str) -> List[model.Literal]
+    /// 
+    /// Gets the literals an individual is connected to by a data property.
+    fn get_data_property_values(
+        &mut self,
+        individual: String,
+        property: String,
+    ) -> PyResult<Vec<model::Literal>> {
+        let iri = self.build.iri(individual);
+        let dp = self.build.data_property(property);
+
+        let values = self
+            .ontology
+            .axiom_for_iri(&iri)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::DataPropertyAssertion(DataPropertyAssertion {
+                    dp: p,
+                    from: Individual::Named(from),
+                    to,
+                }) if p == &dp && from.0 == iri => Some(model::Literal::from(to)),
+                _ => None,
+            })
+            .collect();
+
+        Ok(values)
+    }
+
+    /// get_data_properties(self) -> Set[str]
+    /// 
+    /// Returns the IRIs of all declared data properties in the ontology.
+    fn get_data_properties(&mut self) -> PyResult<HashSet<String>> {
+        let data_properties = self
+            .ontology
+            .axiom_for_kind(AxiomKind::DeclareDataProperty)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::DeclareDataProperty(ddp) => Some(ddp.0 .0.to_string()),
+                _ => None,
+            })
+            .collect();
+        Ok(data_properties)
+    }
+
+    /// get_annotation_properties(self) -> Set[str]
+    /// 
+    /// Returns the IRIs of all declared annotation properties in the ontology.
+    fn get_annotation_properties(&mut self) -> PyResult<HashSet<String>> {
+        let annotation_properties = self
+            .ontology
+            .axiom_for_kind(AxiomKind::DeclareAnnotationProperty)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::DeclareAnnotationProperty(dap) => Some(dap.0 .0.to_string()),
+                _ => None,
+            })
+            .collect();
+        Ok(annotation_properties)
+    }
+
+    /// get_named_individuals(self) -> Set[str]
+    /// 
+    /// Returns the IRIs of all declared named individuals in the ontology.
+    fn get_named_individuals(&mut self) -> PyResult<HashSet<String>> {
+        let named_individuals = self
+            .ontology
+            .axiom_for_kind(AxiomKind::DeclareNamedIndividual)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::DeclareNamedIndividual(dni) => Some(dni.0 .0.to_string()),
+                _ => None,
+            })
+            .collect();
+        Ok(named_individuals)
+    }
+
+    /// get_datatypes(self) -> Set[str]
+    /// 
+    /// Returns the IRIs of all declared datatypes in the ontology.
+    fn get_datatypes(&mut self) -> PyResult<HashSet<String>> {
+        let datatypes = self
+            .ontology
+            .axiom_for_kind(AxiomKind::DeclareDatatype)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::DeclareDatatype(dd) => Some(dd.0 .0.to_string()),
+                _ => None,
+            })
+            .collect();
+        Ok(datatypes)
+    }
+
+    /// get_signature(self, include_undeclared: bool = True) -> List[Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]
+    /// 
+    /// Returns all entities of the ontology as model objects, sorted by their kind and IRI.
+    /// 
+    /// If `include_undeclared` is true, entities that are used in axioms but never declared are included as well.
+    #[pyo3(signature = (include_undeclared=true))]
+    fn get_signature(&mut self, py: Python, include_undeclared: bool) -> PyResult<Vec<PyObject>> {
+        let mut entities: BTreeSet<(&'static str, IRI<ArcStr>)> = BTreeSet::new();
+
+        for kind in [
+            AxiomKind::DeclareClass,
+            AxiomKind::DeclareObjectProperty,
+            AxiomKind::DeclareDataProperty,
+            AxiomKind::DeclareAnnotationProperty,
+            AxiomKind::DeclareNamedIndividual,
+            AxiomKind::DeclareDatatype,
+        ] {
+            for aax in self.ontology.axiom_for_kind(kind) {
+                //Only the declared entity, not the entities in the annotations of the declaration
+                let entity = match &aax.axiom {
+                    Axiom::DeclareClass(DeclareClass(c)) => ("Class", c.0.clone()),
+                    Axiom::DeclareObjectProperty(DeclareObjectProperty(op)) => {
+                        ("ObjectProperty", op.0.clone())
+                    }
+                    Axiom::DeclareDataProperty(DeclareDataProperty(dp)) => {
+                        ("DataProperty", dp.0.clone())
+                    }
+                    Axiom::DeclareAnnotationProperty(DeclareAnnotationProperty(ap)) => {
+                        ("AnnotationProperty", ap.0.clone())
+                    }
+                    Axiom::DeclareNamedIndividual(DeclareNamedIndividual(ni)) => {
+                        ("NamedIndividual", ni.0.clone())
+                    }
+                    Axiom::DeclareDatatype(DeclareDatatype(dt)) => ("Datatype", dt.0.clone()),
+                    _ => continue,
+                };
+                entities.insert(entity);
+            }
+        }
+
+        if include_undeclared {
+            for aax in self.ontology.iter() {
+                aax.walk_entities(None, &mut |kind, iri| {
+                    entities.insert((kind, iri.clone()));
+                });
+            }
+        }
+
+        Ok(entities
+            .into_iter()
+            .map(|(kind, iri)| entity_into_py(py, kind, iri))
+            .collect())
+    }
+
+    /// get_annotation(self, class_iri: str, ann_iri: str) -> Optional[str]
+    /// 
+    /// Gets the first annotated value for an entity and annotation property.
+    /// 
+    /// Note: If there are multiple annotation axioms for the queried entity and annotation property,
+    /// the order is neither necessarily the same as in the ontology neither is it stable.
+    /// Get all annotation values with `PyIndexedOntology.get_annotations`.
+    fn get_annotation(
+        &mut self,
+        py: Python,
+        class_iri: String,
+        ann_iri: String,
+    ) -> PyResult<PyObject> {
+        let annots = self.get_annotations(class_iri, ann_iri);
+
+        let mut literal_value = ().to_object(py);
+
+        if let Ok(literal_values) = annots {
+            if !literal_values.is_empty() {
+                literal_value = literal_values.into_iter().next().to_object(py);
+            }
+        }
+
+        Ok(literal_value)
+    }
+
+    /// get_annotations(self, class_iri: str, ann_iri: str) -> List[str]
+    /// 
+    /// Gets all annotated value for an entity and annotation property.
+    /// 
+    /// Note: The order is neither necessarily the same as in the ontology neither is it stable.
+    /// Get all annotation values with `PyIndexedOntology.get_annotations`.
+    fn get_annotations(&mut self, class_iri: String, ann_iri: String) -> PyResult<Vec<String>> {
+        let iri = self.build.iri(class_iri);
+
+        let literal_values : Vec<String> = self.ontology.axiom_for_iri(&iri)
+                                .filter_map(|aax: &AnnotatedAxiom<ArcStr>| {
+            match &aax.axiom {
+                Axiom::AnnotationAssertion(AnnotationAssertion{subject:_,ann}) => {
+                        match ann {
+                            Annotation {ap, av:  AnnotationValue::Literal(Literal::Simple{literal}) } => {
+                                if ann_iri.eq(&ap.0.to_string()) {
+                                    Some(literal.clone())
+                                } else {
+                                    None
+                                }
+                            },
+                            //Language { literal: String, lang: String },
+                            Annotation {ap, av:  AnnotationValue::Literal(Literal::Language{literal, lang:_}) } => {
+                                if ann_iri.eq(&ap.0.to_string()) {
+                                    Some(literal.clone())
                                 } else {
                                     None
                                 }
@@ -348,121 +1131,982 @@ impl PyIndexedOntology {
                 }
         }).collect();
 
-        Ok(literal_values)
+        Ok(literal_values)
+    }
+
+    /// pretty(self, obj: typing.Any, width: int = 80) -> str
+    /// 
+    /// Renders a model object with the labels of the entities it references.
+    /// 
+    /// Entities without a label are shown by their IRI, lines longer than `width` are broken
+    /// between the arguments of nested objects.
+    #[pyo3(signature = (obj, width=80))]
+    fn pretty(&mut self, obj: &PyAny, width: usize) -> PyResult<String> {
+        let term = model::Term::from_py(obj).ok_or_else(|| {
+            PyTypeError::new_err(format!("Cannot render {}", obj.get_type().name().unwrap_or("?")))
+        })?;
+
+        Ok(term.render(&|iri| self.label_of(iri), width))
+    }
+
+    /// save_to_file(self, file_name: str, format: Optional[str] = None) -> None
+    /// 
+    /// Saves the ontology to disk in owx format or, if `format` is `"obographs"`, as OBO Graphs JSON.
+    /// 
+    /// Without `format`, files ending in `.json` are saved as OBO Graphs JSON.
+    /// The ontology iri, version iri and ontology annotations are written as well.
+    #[pyo3(signature = (file_name, format=None))]
+    fn save_to_file(&mut self, file_name: String, format: Option<String>) -> PyResult<()> {
+        let format = format.unwrap_or_else(|| {
+            if file_name.ends_with(".json") { "obographs" } else { "owx" }.to_string()
+        });
+        if format != "owx" && format != "obographs" {
+            return Err(PyValueError::new_err(format!(
+                "Unknown format '{}'. Expected 'owx' or 'obographs'.",
+                format
+            )));
+        }
+
+        let mut file = File::create(file_name)?;
+        if format == "obographs" {
+            serde_json::to_writer_pretty(file, &obographs::write(&self.ontology))
+                .map_err(|e| PyValueError::new_err(format!("Problem saving the ontology to a file: {}", e)))
+        } else {
+            horned_owl::io::owx::writer::write(&mut file, &self.axiom_mapped(), Some(&self.mapping))
+                .map_err(|e| PyValueError::new_err(format!("Problem saving the ontology to a file: {:?}", e)))
+        }
+    }
+
+    /// get_axioms_for_iri(self, iri: str) -> List[model.AnnotatedAxiom]
+    /// 
+    /// Gets all axioms for an entity.
+    fn get_axioms_for_iri(&mut self, py: Python<'_>, iri: String) -> PyResult<Vec<PyObject>> {
+        let b = Build::new();
+        let iri = b.iri(iri);
+
+        let axioms = self
+            .ontology
+            .axiom_for_iri(&iri)
+            .map(|a| model::AnnotatedAxiom::from(a))
+            .map(|a: model::AnnotatedAxiom| a.into_py(py) )
+            .collect();
+
+        Ok(axioms)
+    }
+
+    /// get_axioms(self) -> List[model.AnnotatedAxioms]
+    /// 
+    /// Returns all axioms of the ontology.
+    fn get_axioms(&mut self, py: Python) -> PyResult<Vec<PyObject>> {
+        let r = self
+            .ontology
+            .iter()
+            .map(|a| a.clone().into())
+            .map(|a: model::AnnotatedAxiom| a.into_py(py))
+            .collect();
+
+        Ok(r)
+    }
+
+    /// iter_axioms(self) -> Iterator[model.AnnotatedAxiom]
+    /// 
+    /// Iterates over all axioms of the ontology.
+    /// 
+    /// Unlike `PyIndexedOntology.get_axioms`, the axioms are converted to Python objects one at a time.
+    /// Changing the axioms of the ontology while iterating raises a `RuntimeError`.
+    fn iter_axioms(slf: PyRef<'_, Self>) -> PyResult<AxiomIterator> {
+        Ok(AxiomIterator {
+            revision: slf.revision,
+            axioms: slf.shared_axioms(None).into_iter(),
+            ontology: slf.into(),
+        })
+    }
+
+    /// iter_axioms_for_iri(self, iri: str) -> Iterator[model.AnnotatedAxiom]
+    /// 
+    /// Iterates over all axioms for an entity.
+    /// 
+    /// Like `PyIndexedOntology.iter_axioms`, the axioms are converted to Python objects one at a time.
+    fn iter_axioms_for_iri(slf: PyRef<'_, Self>, iri: String) -> PyResult<AxiomIterator> {
+        let iri = slf.build.iri(iri);
+
+        Ok(AxiomIterator {
+            revision: slf.revision,
+            axioms: slf.shared_axioms(Some(&iri)).into_iter(),
+            ontology: slf.into(),
+        })
+    }
+
+    /// get_axioms_of_kind(self, kind: Union[str, type]) -> List[model.AnnotatedAxiom]
+    /// 
+    /// Returns all axioms of a kind.
+    /// 
+    /// The kind is given by the name of the axiom class, e.g. `"SubClassOf"`, or by the class itself, e.g. `model.SubClassOf`.
+    fn get_axioms_of_kind(&mut self, py: Python, kind: &PyAny) -> PyResult<Vec<PyObject>> {
+        let name: &str = if let Ok(ty) = kind.downcast::<PyType>() {
+            ty.name()?
+        } else {
+            kind.extract()?
+        };
+
+        let kind = axiom_kind(name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown axiom kind '{}'", name)))?;
+
+        let axioms = self
+            .ontology
+            .axiom_for_kind(kind)
+            .map(|a| model::AnnotatedAxiom::from(a))
+            .map(|a: model::AnnotatedAxiom| a.into_py(py))
+            .collect();
+
+        Ok(axioms)
+    }
+
+    /// count_axioms_by_kind(self) -> Dict[str, int]
+    /// 
+    /// Returns the number of axioms for each axiom kind occurring in the ontology.
+    fn count_axioms_by_kind(&mut self) -> PyResult<HashMap<String, usize>> {
+        let counts = AXIOM_KINDS
+            .iter()
+            .map(|(name, kind)| (name.to_string(), self.ontology.axiom_for_kind(*kind).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        Ok(counts)
+    }
+
+    /// match_axioms(self, pattern: Union[Pattern, model.Axiom, model.AnnotatedAxiom]) -> List[Dict[str, typing.Any]]
+    /// 
+    /// Finds all axioms matching the pattern and returns the variable bindings of each match.
+    ///
+    /// Only axioms of the kind given by the pattern are considered. Patterns of kind
+    /// `AnnotatedAxiom` match the axioms together with their annotations.
+    ///
+    /// An axiom yields one result for every distinct way it matches, so a pattern over an
+    /// unordered list such as `DisjointClasses([Var("x"), Var("y")])` yields each assignment
+    /// of the classes to `x` and `y`.
+    fn match_axioms(&mut self, py: Python, pattern: &PyAny) -> PyResult<Vec<PyObject>> {
+        let pattern = query::Pat::from_py(pattern)?;
+        let annotated = pattern.kind() == Some("AnnotatedAxiom");
+
+        let axioms: Box<dyn Iterator<Item = &AnnotatedAxiom<ArcStr>> + '_> =
+            match pattern.kind().and_then(axiom_kind) {
+                Some(kind) => Box::new(self.ontology.axiom_for_kind(kind)),
+                None => Box::new(self.ontology.iter()),
+            };
+
+        let mut matches = Vec::new();
+        for aax in axioms {
+            let term = if annotated {
+                model::AnnotatedAxiom::from(aax).to_term()
+            } else {
+                model::Axiom::from(&aax.axiom).to_term()
+            };
+
+            for bindings in pattern.matches(&term) {
+                let dict = PyDict::new(py);
+                for (name, value) in bindings {
+                    dict.set_item(name, value.into_py(py)?)?;
+                }
+                matches.push(dict.into());
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// sparql(self, query: str) -> List[tuple]
+    /// 
+    /// Evaluates a SPARQL SELECT query over the RDF representation of the ontology.
+    ///
+    /// Supports basic graph patterns, `FILTER`, `OPTIONAL`, `DISTINCT`, `LIMIT` and `OFFSET`.
+    /// Prefixes not declared in the query are expanded with the prefix mappings of the ontology.
+    /// Each row holds the values of the selected variables: IRIs as strings, blank nodes as
+    /// `_:id`, literals as Python values and unbound variables as `None`.
+    fn sparql(&mut self, py: Python, query: String) -> PyResult<Vec<PyObject>> {
+        let query = sparql::Query::parse(&query, &self.mapping).map_err(PyValueError::new_err)?;
+        if self.rdf_graph.is_none() {
+            self.rdf_graph = Some(rdf::Graph::from_ontology(&self.ontology));
+        }
+        let graph = self.rdf_graph.as_ref().unwrap();
+
+        let rows = query
+            .evaluate(graph)
+            .into_iter()
+            .map(|row| PyTuple::new(py, row.into_iter().map(|v| v.into_py(py))).into())
+            .collect();
+
+        Ok(rows)
+    }
+
+    /// get_referencing_axioms(self, iri: str, role: str = "any") -> List[model.AnnotatedAxiom]
+    /// 
+    /// Gets the axioms referencing an entity in the given role.
+    ///
+    /// With `role="subject"` only the axioms about the entity are returned, e.g. its declaration,
+    /// its annotations or the axioms with the entity as subclass or asserted individual. With
+    /// `role="filler"` only the axioms using the entity anywhere else are returned, e.g. as a
+    /// superclass, nested in a class expression or in an axiom annotation. `role="any"` returns both.
+    #[pyo3(signature = (iri, role = "any"))]
+    fn get_referencing_axioms(
+        &mut self,
+        py: Python,
+        iri: String,
+        role: &str,
+    ) -> PyResult<Vec<PyObject>> {
+        let (subject, filler) = match role {
+            "any" => (true, true),
+            "subject" => (true, false),
+            "filler" => (false, true),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown role '{}'. Expected 'any', 'subject' or 'filler'.",
+                    role
+                )))
+            }
+        };
+
+        let iri = self.build.iri(iri);
+        let axioms = self
+            .referencing_axioms(&iri, subject, filler)
+            .into_iter()
+            .map(|aax| model::AnnotatedAxiom::from(aax).into_py(py))
+            .collect();
+
+        Ok(axioms)
+    }
+
+    /// usage_count(self, iri: str) -> int
+    /// 
+    /// Counts the axioms using an entity as a filler.
+    ///
+    /// These are the axioms which would be affected by deleting the entity apart from the
+    /// axioms about the entity itself. See `PyIndexedOntology.get_referencing_axioms`.
+    fn usage_count(&mut self, iri: String) -> PyResult<usize> {
+        let iri = self.build.iri(iri);
+
+        Ok(self.referencing_axioms(&iri, false, true).len())
+    }
+
+    /// rename_entity(self, old_iri: str, new_iri: str) -> int
+    /// 
+    /// Replaces every occurrence of an IRI in the ontology.
+    ///
+    /// See `PyIndexedOntology.rename_entities`.
+    fn rename_entity(&mut self, old_iri: String, new_iri: String) -> PyResult<usize> {
+        let mut mapping = HashMap::new();
+        mapping.insert(old_iri, new_iri);
+
+        self.rename_entities(mapping)
+    }
+
+    /// rename_entities(self, mapping: Dict[str, str]) -> int
+    /// 
+    /// Replaces every occurrence of the IRIs in `mapping` by the IRIs they map to.
+    ///
+    /// Rewrites all axioms including nested class expressions, annotation subjects and values
+    /// and axiom annotations. All IRIs are replaced at once, so the mapping may swap IRIs or
+    /// map an IRI to another renamed IRI. Axioms which become equal after renaming are merged.
+    /// Returns the number of rewritten axioms.
+    fn rename_entities(&mut self, mapping: HashMap<String, String>) -> PyResult<usize> {
+        let mapping: HashMap<IRI<ArcStr>, IRI<ArcStr>> = mapping
+            .into_iter()
+            .map(|(old, new)| (self.build.iri(old), self.build.iri(new)))
+            .filter(|(old, new)| old != new)
+            .collect();
+
+        let affected: BTreeSet<AnnotatedAxiom<ArcStr>> = mapping
+            .keys()
+            .flat_map(|iri| self.ontology.axiom_for_iri(iri).cloned())
+            .collect();
+
+        //All affected axioms are removed before any renamed axiom is added, as a renamed axiom
+        //may equal another affected axiom, e.g. when two IRIs are swapped
+        for aax in &affected {
+            self.remove_annotated_axiom(aax);
+        }
+
+        for aax in &affected {
+            let mut renamed = model::AnnotatedAxiom::from(aax);
+            renamed.map_iris(&mut |iri| {
+                if let Some(new) = mapping.get(iri) {
+                    *iri = new.clone();
+                }
+            });
+            self.add_annotated_axiom(renamed.into());
+        }
+
+        Ok(affected.len())
+    }
+
+    /// remove_entity(self, iri: str, cascade: bool = True) -> List[model.AnnotatedAxiom]
+    /// 
+    /// Removes an entity from the ontology and returns the removed axioms.
+    ///
+    /// Removes the declarations of the entity and the annotation assertions about it. If `cascade`
+    /// is set, the logical axioms using the entity are removed as well. Annotation assertions of
+    /// other terms with the entity as value and axioms only using it in their annotations are kept.
+    #[pyo3(signature = (iri, cascade = true))]
+    fn remove_entity(&mut self, py: Python, iri: String, cascade: bool) -> PyResult<Vec<PyObject>> {
+        let iri = self.build.iri(iri);
+
+        let removed: Vec<AnnotatedAxiom<ArcStr>> = self
+            .ontology
+            .axiom_for_iri(&iri)
+            .filter(|aax| match aax.kind() {
+                AxiomKind::DeclareClass
+                | AxiomKind::DeclareObjectProperty
+                | AxiomKind::DeclareDataProperty
+                | AxiomKind::DeclareAnnotationProperty
+                | AxiomKind::DeclareNamedIndividual
+                | AxiomKind::DeclareDatatype => in_signature(&aax.axiom, &iri),
+                AxiomKind::AnnotationAssertion => matches!(
+                    &aax.axiom,
+                    Axiom::AnnotationAssertion(AnnotationAssertion {
+                        subject: AnnotationSubject::IRI(subject),
+                        ..
+                    }) if subject == &iri
+                ),
+                kind => cascade && is_logical(kind) && in_signature(&aax.axiom, &iri),
+            })
+            .cloned()
+            .collect();
+
+        for aax in &removed {
+            self.remove_annotated_axiom(aax);
+        }
+
+        Ok(removed
+            .iter()
+            .map(|aax| model::AnnotatedAxiom::from(aax).into_py(py))
+            .collect())
+    }
+
+    /// obsolete(self, iri: str, replaced_by: Optional[str] = None, consider: List[str] = [], reason: Optional[Union[str, model.IRI, model.Literal]] = None) -> List[model.AnnotatedAxiom]
+    /// 
+    /// Marks a term as obsolete following the OBO conventions and returns the removed axioms.
+    ///
+    /// Sets `owl:deprecated` to true, prefixes the labels of the term with "obsolete " and removes
+    /// all logical axioms using the term. `replaced_by` is set as IAO:0100001 (term replaced by),
+    /// each term in `consider` is added as oboInOwl:consider and `reason` is set as IAO:0000231
+    /// (has obsolescence reason). Terms may be given as IRIs or CURIEs. A `reason` string which is an
+    /// IRI or a CURIE such as `IAO:0000227` is stored as IRI, other values as described for
+    /// `PyIndexedOntology.set_annotation`.
+    ///
+    /// CURIEs are expanded with the prefix mappings of the ontology. CURIEs of OBO ontologies such
+    /// as GO or IAO are expanded to OBO PURLs without a mapping. A `ValueError` is raised for
+    /// other CURIEs without a prefix mapping, before the ontology is changed.
+    #[pyo3(signature = (iri, replaced_by=None, consider=Vec::new(), reason=None))]
+    fn obsolete(
+        &mut self,
+        py: Python,
+        iri: String,
+        replaced_by: Option<String>,
+        consider: Vec<String>,
+        reason: Option<AnnotationValueArg>,
+    ) -> PyResult<Vec<PyObject>> {
+        let term = self.build.iri(iri.clone());
+
+        let replaced_by = match replaced_by {
+            Some(id) => Some(self.iri_value(self.id_to_iri(&id)?.unwrap_or(id))),
+            None => None,
+        };
+        let consider = consider
+            .into_iter()
+            .map(|id| Ok(self.iri_value(self.id_to_iri(&id)?.unwrap_or(id))))
+            .collect::<PyResult<Vec<_>>>()?;
+        let reason = match reason {
+            Some(AnnotationValueArg::String(id)) => Some(match self.id_to_iri(&id)? {
+                Some(iri) => self.iri_value(iri),
+                None => AnnotationValueArg::String(id),
+            }),
+            reason => reason,
+        };
+
+        let removed: Vec<AnnotatedAxiom<ArcStr>> = self
+            .ontology
+            .axiom_for_iri(&term)
+            .filter(|aax| is_logical(aax.kind()) && in_signature(&aax.axiom, &term))
+            .cloned()
+            .collect();
+        for aax in &removed {
+            self.remove_annotated_axiom(aax);
+        }
+
+        let labels: Vec<AnnotatedAxiom<ArcStr>> = self
+            .ontology
+            .axiom_for_iri(&term)
+            .filter(|aax| match &aax.axiom {
+                Axiom::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(subject),
+                    ann:
+                        Annotation {
+                            ap,
+                            av: AnnotationValue::Literal(_),
+                        },
+                }) => subject == &term && AnnotationBuiltIn::LABEL.iri_s().eq(&ap.0.to_string()),
+                _ => false,
+            })
+            .cloned()
+            .collect();
+        for aax in labels {
+            if let Axiom::AnnotationAssertion(mut assertion) = aax.axiom.clone() {
+                if let AnnotationValue::Literal(value) = &mut assertion.ann.av {
+                    let text = match value {
+                        Literal::Simple { literal }
+                        | Literal::Language { literal, .. }
+                        | Literal::Datatype { literal, .. } => literal,
+                    };
+                    if text.starts_with("obsolete ") {
+                        continue;
+                    }
+                    *text = format!("obsolete {}", text);
+                }
+
+                self.remove_annotated_axiom(&aax);
+                self.add_annotated_axiom(AnnotatedAxiom {
+                    axiom: Axiom::AnnotationAssertion(assertion),
+                    ann: aax.ann.clone(),
+                });
+            }
+        }
+
+        self.set_annotation(
+            iri.clone(),
+            OWL_DEPRECATED.to_string(),
+            AnnotationValueArg::String("true".to_string()),
+            None,
+            Some(XSD_BOOLEAN.to_string()),
+            true,
+        )?;
+
+        if let Some(value) = replaced_by {
+            self.set_annotation(iri.clone(), IAO_REPLACED_BY.to_string(), value, None, None, true)?;
+        }
+
+        for value in consider {
+            self.add_annotation(iri.clone(), OBO_CONSIDER.to_string(), value, None, None)?;
+        }
+
+        if let Some(reason) = reason {
+            self.set_annotation(iri, IAO_OBSOLESCENCE_REASON.to_string(), reason, None, None, true)?;
+        }
+
+        Ok(removed
+            .iter()
+            .map(|aax| model::AnnotatedAxiom::from(aax).into_py(py))
+            .collect())
+    }
+
+    /// get_obsolete_terms(self) -> Set[str]
+    /// 
+    /// Gets the IRIs of all terms with `owl:deprecated` set to true.
+    fn get_obsolete_terms(&mut self) -> PyResult<HashSet<String>> {
+        let deprecated = self.build.annotation_property(OWL_DEPRECATED);
+
+        let terms = self
+            .ontology
+            .axiom_for_kind(AxiomKind::AnnotationAssertion)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(subject),
+                    ann:
+                        Annotation {
+                            ap,
+                            av:
+                                AnnotationValue::Literal(
+                                    Literal::Simple { literal }
+                                    | Literal::Datatype { literal, .. },
+                                ),
+                        },
+                }) if ap == &deprecated && (literal == "true" || literal == "1") => {
+                    Some(subject.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(terms)
+    }
+
+    /// add_axiom(self, ax: model.Axiom, annotations: Optional[List[model.Annotation]]) -> None
+    /// 
+    /// Adds an axiom to the ontology with optional annotations.
+    fn add_axiom(
+        &mut self,
+        ax: model::Axiom,
+        annotations: Option<BTreeSet<model::Annotation>>,
+    ) -> PyResult<()> {
+        let ann: model::BTreeSetWrap<model::Annotation> = annotations.unwrap_or(BTreeSet::new()).into();
+        let annotated_axiom = model::AnnotatedAxiom {
+            axiom: ax,
+            ann,
+        };
+        self.add_annotated_axioms(vec![annotated_axiom.into()]);
+
+        Ok(())
+    }
+
+    /// add_axioms(self, axioms: Iterable[Union[model.Axiom, model.AnnotatedAxiom]]) -> int
+    /// 
+    /// Adds all axioms of an iterable to the ontology and returns the number of added axioms.
+    /// 
+    /// The iterable is consumed lazily, so generators can be used. The GIL is released while the
+    /// axioms are inserted and indexed.
+    fn add_axioms(&mut self, py: Python, axioms: &PyAny) -> PyResult<usize> {
+        let mut annotated: Vec<AnnotatedAxiom<ArcStr>> = Vec::new();
+        for ax in axioms.iter()? {
+            annotated.push(match ax?.extract::<AxiomArg>()? {
+                AxiomArg::Annotated(aax) => aax.into(),
+                AxiomArg::Axiom(ax) => Axiom::<ArcStr>::from(ax).into(),
+            });
+        }
+
+        Ok(py.allow_threads(|| self.add_annotated_axioms(annotated)))
+    }
+
+    /// remove_axiom(self, ax: Union[model.Axiom, model.AnnotatedAxiom]) -> int
+    /// 
+    /// Removes an axiom from the ontology and returns the number of removed axioms.
+    /// 
+    /// A `model.AnnotatedAxiom` is only removed if its annotations match as well. A `model.Axiom`
+    /// removes the axiom with any annotations.
+    fn remove_axiom(&mut self, ax: AxiomArg) -> PyResult<usize> {
+        Ok(match ax {
+            AxiomArg::Annotated(aax) => self.remove_annotated_axioms(vec![aax.into()], vec![]),
+            AxiomArg::Axiom(ax) => self.remove_annotated_axioms(vec![], vec![ax.into()]),
+        })
+    }
+
+    /// remove_axioms(self, axioms: Iterable[Union[model.Axiom, model.AnnotatedAxiom]]) -> int
+    /// 
+    /// Removes all axioms of an iterable from the ontology and returns the number of removed axioms.
+    /// 
+    /// Axioms are matched as in `PyIndexedOntology.remove_axiom`. The iterable is consumed lazily,
+    /// so generators can be used. The GIL is released while the axioms are removed.
+    fn remove_axioms(&mut self, py: Python, axioms: &PyAny) -> PyResult<usize> {
+        let mut exact: Vec<AnnotatedAxiom<ArcStr>> = Vec::new();
+        let mut any: Vec<Axiom<ArcStr>> = Vec::new();
+        for ax in axioms.iter()? {
+            match ax?.extract::<AxiomArg>()? {
+                AxiomArg::Annotated(aax) => exact.push(aax.into()),
+                AxiomArg::Axiom(ax) => any.push(ax.into()),
+            }
+        }
+
+        Ok(py.allow_threads(|| self.remove_annotated_axioms(exact, any)))
+    }
+
+    /// iri(self, iri: str) -> model.IRI
+    /// 
+    /// Creates an new IRI from string.
+    /// 
+    /// Use this method instead of  `model.IRI.parse` if possible as it is more optimized using caches.
+    fn iri(&self, iri: String) -> model::IRI {
+        model::IRI::new(iri, &self.build)
+    }
+}
+impl PyIndexedOntology {
+    /// An IRI as value for `set_annotation` and `add_annotation`.
+    fn iri_value(&self, iri: String) -> AnnotationValueArg {
+        AnnotationValueArg::Value(AnnotationValue::IRI(self.build.iri(iri)).into())
+    }
+
+    /// The IRI of an IRI or CURIE given as string, or `None` if `id` is neither.
+    ///
+    /// CURIEs of OBO ontologies without a prefix mapping are expanded to OBO PURLs. Returns an
+    /// error for other CURIEs without a prefix mapping.
+    fn id_to_iri(&self, id: &str) -> PyResult<Option<String>> {
+        if id.contains(char::is_whitespace) {
+            return Ok(None);
+        }
+        if id.contains("://") {
+            return Ok(Some(id.to_string()));
+        }
+
+        let (prefix, reference) = match id.split_once(':') {
+            Some((prefix, reference))
+                if !prefix.is_empty()
+                    && !reference.is_empty()
+                    && prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+            {
+                (prefix, reference)
+            }
+            _ => return Ok(None),
+        };
+
+        match self.mapping.expand_curie(&Curie::new(Some(prefix), reference)) {
+            Ok(iri) => Ok(Some(iri)),
+            Err(_) if OBO_PREFIXES.contains(&prefix) => {
+                Ok(Some(format!("{}{}_{}", OBO_PURL, prefix, reference)))
+            }
+            Err(_) => Err(PyValueError::new_err(format!(
+                "No prefix mapping for '{}' in '{}'",
+                prefix, id
+            ))),
+        }
+    }
+
+    fn annotation_value(
+        &self,
+        value: AnnotationValueArg,
+        lang: Option<String>,
+        datatype: Option<String>,
+    ) -> PyResult<AnnotationValue<ArcStr>> {
+        match value {
+            AnnotationValueArg::Value(av) => {
+                if lang.is_some() || datatype.is_some() {
+                    Err(PyValueError::new_err(
+                        "lang and datatype can only be used with string values",
+                    ))
+                } else {
+                    Ok(av.into())
+                }
+            }
+            AnnotationValueArg::String(literal) => match (lang, datatype) {
+                (None, None) => Ok(AnnotationValue::Literal(Literal::Simple { literal })),
+                (Some(lang), None) => Ok(AnnotationValue::Literal(Literal::Language {
+                    literal,
+                    lang,
+                })),
+                (None, Some(datatype)) => Ok(AnnotationValue::Literal(Literal::Datatype {
+                    literal,
+                    datatype_iri: self.build.iri(datatype),
+                })),
+                (Some(_), Some(_)) => Err(PyValueError::new_err(
+                    "lang and datatype cannot be used together",
+                )),
+            },
+        }
+    }
+
+    /// Returns the `AnnotationAssertion` axioms of an annotation property about a term.
+    fn annotation_assertions(
+        &self,
+        iri: &IRI<ArcStr>,
+        ap: &AnnotationProperty<ArcStr>,
+    ) -> Vec<AnnotatedAxiom<ArcStr>> {
+        self.ontology
+            .axiom_for_iri(iri)
+            .filter(|aax| match &aax.axiom {
+                Axiom::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(subject),
+                    ann,
+                }) => subject == iri && &ann.ap == ap,
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn direct_instances(&self, class: &IRI<ArcStr>) -> HashSet<String> {
+        self.ontology
+            .axiom_for_iri(class)
+            .filter_map(|aax| match &aax.axiom {
+                Axiom::ClassAssertion(ClassAssertion {
+                    ce: ClassExpression::Class(c),
+                    i: Individual::Named(i),
+                }) if &c.0 == class => Some(i.0.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The axioms of the entity `iri` or of the whole ontology, sharing them instead of copying.
+    fn shared_axioms(&self, iri: Option<&IRI<ArcStr>>) -> Vec<Arc<AnnotatedAxiom<ArcStr>>> {
+        let axioms: Box<dyn Iterator<Item = &AnnotatedAxiom<ArcStr>> + '_> = match iri {
+            Some(iri) => Box::new(self.ontology.axiom_for_iri(iri)),
+            None => Box::new(self.ontology.iter()),
+        };
+
+        axioms
+            .map(|aax| {
+                let ptr: *const AnnotatedAxiom<ArcStr> = aax;
+                // SAFETY: `ArcIRIMappedOntology` stores each axiom in an `Arc`, so `ptr` points to
+                // the value of an `Arc` kept alive by the ontology while `aax` is borrowed. The
+                // strong count is incremented first, so the new `Arc` owns its own reference.
+                unsafe {
+                    Arc::increment_strong_count(ptr);
+                    Arc::from_raw(ptr)
+                }
+            })
+            .collect()
+    }
+
+    /// Copies the axioms into an `AxiomMappedOntology` as that is what horned owl writes.
+    fn axiom_mapped(&self) -> ArcAxiomMappedOntology {
+        let mut amo: ArcAxiomMappedOntology = AxiomMappedOntology::new_arc();
+        let oid = &self.ontology.id().clone();
+
+        amo.mut_id().iri = oid.iri.clone();
+        amo.mut_id().viri = oid.viri.clone();
+        //Ontology annotations are OntologyAnnotation axioms, so they are copied here too
+        for aax in self.ontology.iter() {
+            amo.insert(aax.clone());
+        }
+
+        amo
+    }
+
+    /// Serialises the ontology in owx format.
+    fn to_owx(&self) -> PyResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        horned_owl::io::owx::writer::write(&mut buffer, &self.axiom_mapped(), Some(&self.mapping))
+            .map_err(|e| PyValueError::new_err(format!("Unable to serialise ontology: {:?}", e)))?;
+
+        Ok(buffer)
+    }
+
+    /// Reads an ontology serialised by `to_owx` and builds its indexes.
+    fn from_owx(owx: &[u8], hierarchy_mode: HierarchyMode) -> PyResult<PyIndexedOntology> {
+        let b = Build::new_arc();
+        let (o, m) = horned_owl::io::owx::reader::read_with_build(&mut BufReader::new(owx), &b)
+            .map_err(|e| PyValueError::new_err(format!("Unable to restore ontology: {:?}", e)))?;
+
+        let mut onto = PyIndexedOntology::from(IRIMappedOntology::from(o));
+        onto.mapping = m;
+        if hierarchy_mode != onto.hierarchy_mode {
+            onto.hierarchy_mode = hierarchy_mode;
+            onto.rebuild_hierarchy();
+        }
+
+        Ok(onto)
+    }
+
+    /// The annotation property of a `to_table` column, given by its IRI, CURIE or label.
+    ///
+    /// Labels are only accepted if they belong to a declared annotation property, so that a class
+    /// sharing the label cannot be mistaken for the property.
+    fn column_property(&self, column: &str) -> PyResult<IRI<ArcStr>> {
+        if column == "definition" {
+            return Ok(self.build.iri(IAO_DEFINITION));
+        }
+        if column.contains("://") {
+            return Ok(self.build.iri(column));
+        }
+        if let Some(iri) = self.labels_to_iris.get(column) {
+            if self.is_annotation_property(iri) {
+                return Ok(iri.clone());
+            }
+        }
+
+        let iri = match column.split_once(':') {
+            Some((prefix, reference)) => self
+                .mapping
+                .expand_curie(&Curie::new(Some(prefix), reference))
+                .ok(),
+            None => None,
+        };
+        iri.map(|iri| self.build.iri(iri)).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown column '{}'. Expected one of {}, or the IRI, CURIE or label of an annotation property.",
+                column,
+                TABLE_COLUMNS.join(", ")
+            ))
+        })
+    }
+
+    fn is_annotation_property(&self, iri: &IRI<ArcStr>) -> bool {
+        self.ontology.axiom_for_iri(iri).any(|aax| match &aax.axiom {
+            Axiom::DeclareAnnotationProperty(DeclareAnnotationProperty(ap)) => &ap.0 == iri,
+            _ => false,
+        })
+    }
+
+    /// The value of a `to_table` cell for a class.
+    fn table_cell(
+        &self,
+        class: &IRI<ArcStr>,
+        column: &str,
+        properties: &HashMap<String, IRI<ArcStr>>,
+    ) -> PyResult<String> {
+        Ok(match column {
+            "iri" => class.to_string(),
+            "curie" => self
+                .mapping
+                .shrink_iri(&class.to_string())
+                .map(|curie| curie.to_string())
+                .unwrap_or_default(),
+            "label" => self.label_of(class).unwrap_or_default(),
+            "parents" => {
+                let parents: BTreeSet<String> = self
+                    .classes_to_superclasses
+                    .get(class)
+                    .into_iter()
+                    .flatten()
+                    .map(|sup| {
+                        self.label_of(sup).unwrap_or_else(|| {
+                            self.mapping
+                                .shrink_iri(&sup.to_string())
+                                .map(|curie| curie.to_string())
+                                .unwrap_or_else(|_| sup.to_string())
+                        })
+                    })
+                    .collect();
+                parents.into_iter().collect::<Vec<_>>().join("|")
+            }
+            _ => {
+                let property = properties.get(column).ok_or_else(|| {
+                    PyValueError::new_err(format!("Unknown column '{}'.", column))
+                })?;
+                let values: BTreeSet<String> = self
+                    .ontology
+                    .axiom_for_iri(class)
+                    .filter_map(|aax| match &aax.axiom {
+                        Axiom::AnnotationAssertion(AnnotationAssertion {
+                            subject: AnnotationSubject::IRI(subject),
+                            ann:
+                                Annotation {
+                                    ap,
+                                    av:
+                                        AnnotationValue::Literal(
+                                            Literal::Simple { literal }
+                                            | Literal::Language { literal, .. }
+                                            | Literal::Datatype { literal, .. },
+                                        ),
+                                },
+                        }) if subject == class && &ap.0 == property => Some(literal.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                values.into_iter().collect::<Vec<_>>().join("|")
+            }
+        })
+    }
+
+    /// The first `rdfs:label` of an entity.
+    fn label_of(&self, iri: &IRI<ArcStr>) -> Option<String> {
+        self.ontology.axiom_for_iri(iri).find_map(|aax| match &aax.axiom {
+            Axiom::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann:
+                    Annotation {
+                        ap,
+                        av:
+                            AnnotationValue::Literal(
+                                Literal::Simple { literal } | Literal::Language { literal, .. },
+                            ),
+                    },
+            }) if subject == iri && AnnotationBuiltIn::LABEL.iri_s().eq(&ap.0.to_string()) => {
+                Some(literal.clone())
+            }
+            _ => None,
+        })
     }
 
-    /// save_to_file(self, file_name: str) -> None
-    /// 
-    /// Saves the ontology to disk in owx format.
-    fn save_to_file(&mut self, file_name: String) -> PyResult<()> {
-        let before = Instant::now();
+    /// Inserts an axiom into the ontology and updates the indexes.
+    fn add_annotated_axiom(&mut self, ax: AnnotatedAxiom<ArcStr>) -> bool {
+        self.rdf_graph = None;
+        self.revision += 1;
+        self.insert(&ax);
+        self.ontology.insert(ax)
+    }
 
-        let mut file = File::create(file_name)?;
-        let mut amo: ArcAxiomMappedOntology = AxiomMappedOntology::new_arc();
-        let oid = &self.ontology.id().clone();
+    /// Inserts axioms into the ontology, then indexes the new ones. Returns the number of new axioms.
+    fn add_annotated_axioms(&mut self, axioms: Vec<AnnotatedAxiom<ArcStr>>) -> usize {
+        self.rdf_graph = None;
+        self.revision += 1;
+        let added: Vec<AnnotatedAxiom<ArcStr>> = axioms
+            .into_iter()
+            .filter(|ax| self.ontology.insert(ax.clone()))
+            .collect();
 
-        amo.mut_id().iri = oid.iri.clone();
-        amo.mut_id().viri = oid.viri.clone();
-        //Copy the axioms into an AxiomMappedOntology as that is what horned owl writes
-        for aax in self.ontology.iter() {
-            amo.insert(aax.clone());
+        for ax in &added {
+            self.insert(ax);
         }
-        let time_middle = before.elapsed().as_secs();
-        println!(
-            "Finished preparing ontology for saving in {:?} seconds.",
-            time_middle
-        );
-        let before = Instant::now();
 
-        let result = horned_owl::io::owx::writer::write(&mut file, &amo, Some(&self.mapping));
-
-        let time_after = before.elapsed().as_secs();
-        println!(
-            "Finished saving ontology to file in  {:?} seconds.",
-            time_after
-        );
+        added.len()
+    }
 
-        match result {
-            Ok(()) => Ok(()),
-            Err(error) => panic!("Problem saving the ontology to a file: {:?}", error),
+    /// Removes the axioms in `exact` and the axioms in `any` with any annotations.
+    /// Returns the number of removed axioms.
+    fn remove_annotated_axioms(
+        &mut self,
+        exact: Vec<AnnotatedAxiom<ArcStr>>,
+        any: Vec<Axiom<ArcStr>>,
+    ) -> usize {
+        let mut candidates = exact;
+        for ax in &any {
+            candidates.extend(self.annotated_variants(ax));
         }
-    }
 
-    /// get_axioms_for_iri(self, iri: str) -> List[model.AnnotatedAxiom]
-    /// 
-    /// Gets all axioms for an entity.
-    fn get_axioms_for_iri(&mut self, py: Python<'_>, iri: String) -> PyResult<Vec<PyObject>> {
-        let b = Build::new();
-        let iri = b.iri(iri);
+        let mut removed = 0;
+        for aax in &candidates {
+            if self.remove_annotated_axiom(aax) {
+                removed += 1;
+            }
+        }
 
-        let axioms = self
-            .ontology
-            .axiom_for_iri(&iri)
-            .map(|a| model::AnnotatedAxiom::from(a))
-            .map(|a: model::AnnotatedAxiom| a.into_py(py) )
-            .collect();
+        removed
+    }
 
-        Ok(axioms)
+    /// Removes an axiom from the ontology and updates the indexes.
+    fn remove_annotated_axiom(&mut self, ax: &AnnotatedAxiom<ArcStr>) -> bool {
+        if self.ontology.remove(ax) {
+            self.rdf_graph = None;
+            self.revision += 1;
+            self.unindex(ax);
+            true
+        } else {
+            false
+        }
     }
 
-    /// get_axioms(self) -> List[model.AnnotatedAxioms]
-    /// 
-    /// Returns all axioms of the ontology.
-    fn get_axioms(&mut self, py: Python) -> PyResult<Vec<PyObject>> {
-        let r = self
-            .ontology
-            .iter()
-            .map(|a| a.clone().into())
-            .map(|a: model::AnnotatedAxiom| a.into_py(py))
-            .collect();
+    fn unindex(&mut self, ax: &AnnotatedAxiom<ArcStr>) -> () {
+        match &ax.axiom {
+            Axiom::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann:
+                    Annotation {
+                        ap,
+                        av: AnnotationValue::Literal(Literal::Simple { literal }),
+                    },
+            }) => {
+                if AnnotationBuiltIn::LABEL.iri_s().eq(&ap.0.to_string())
+                    && self.labels_to_iris.get(literal) == Some(subject)
+                {
+                    self.labels_to_iris.remove(literal);
+                }
+            }
+            _ => (),
+        }
 
-        Ok(r)
-    }
+        for edge in self.hierarchy_edges(&ax.axiom) {
+            if !self.still_asserted(&edge.0, &edge, Self::hierarchy_edges) {
+                self.remove_hierarchy_edge(&edge.0, &edge.1);
+            }
+        }
 
-    /// add_axiom(self, ax: model.Axiom, annotations: Optional[List[model.Annotation]]) -> None
-    /// 
-    /// Adds an axiom to the ontology with optional annotations.
-    fn add_axiom(
-        &mut self,
-        ax: model::Axiom,
-        annotations: Option<BTreeSet<model::Annotation>>,
-    ) -> PyResult<()> {
-        let ann: model::BTreeSetWrap<model::Annotation> = annotations.unwrap_or(BTreeSet::new()).into();
-        let annotated_axiom = model::AnnotatedAxiom {
-            axiom: ax,
-            ann,
-        };
-        self.ontology.insert(annotated_axiom);
+        for edge in self.property_edges(&ax.axiom) {
+            if !self.still_asserted(&edge.0, &edge, Self::property_edges) {
+                closure::unlink(&mut self.properties_to_subproperties, &edge.1, &edge.0);
+                closure::unlink(&mut self.properties_to_superproperties, &edge.0, &edge.1);
+            }
+        }
 
-        Ok(())
-    }
+        for pair in self.inverse_pairs(&ax.axiom) {
+            if !self.still_asserted(&pair.0, &pair, Self::inverse_pairs) {
+                closure::unlink(&mut self.properties_to_inverses, &pair.0, &pair.1);
+            }
+        }
 
-    /// remove_axiom(self, ax: model.Axiom) -> None
-    /// 
-    /// Removes an axiom from the ontology.
-    fn remove_axiom(&mut self, ax: model::Axiom) -> PyResult<()> {
-        let ax: Axiom<Arc<str>> = ax.into();
-        let annotated = self
-            .ontology
-            .iter()
-            .find(|a| a.axiom == ax)
-            .ok_or(PyValueError::new_err("args"))?
-            .to_owned();
-        self.ontology.remove(&annotated);
+        for chain in self.property_chains(&ax.axiom) {
+            if !self.still_asserted(&chain.0, &chain, Self::property_chains) {
+                if let Some(chains) = self.properties_to_chains.get_mut(&chain.0) {
+                    chains.remove(&chain.1);
+                }
+            }
+        }
 
-        Ok(())
+        for edge in self.relation_edges(&ax.axiom) {
+            if !self.still_asserted(&edge.0, &edge, Self::relation_edges) {
+                if let Some(relations) = self.classes_to_relations.get_mut(&edge.0) {
+                    closure::unlink(relations, &edge.1, &edge.2);
+                }
+            }
+        }
     }
 
-    /// iri(self, iri: str) -> model.IRI
-    /// 
-    /// Creates an new IRI from string.
-    /// 
-    /// Use this method instead of  `model.IRI.parse` if possible as it is more optimized using caches.
-    fn iri(&self, iri: String) -> model::IRI {
-        model::IRI::new(iri, &self.build)
-    }
-}
-impl PyIndexedOntology {
     fn insert(&mut self, ax: &AnnotatedAxiom<ArcStr>) -> () {
         let b = Build::new();
 
@@ -483,36 +2127,268 @@ impl PyIndexedOntology {
                 },
                 _ => (),
             },
-            AxiomKind::SubClassOf => {
-                match ax.clone().axiom {
-                    Axiom::SubClassOf(SubClassOf { sup, sub }) => {
-                        match sup {
-                            ClassExpression::Class(c) => {
-                                match sub {
-                                    ClassExpression::Class(d) => {
-                                        //Direct subclasses only
-                                        let _ = &self
-                                            .classes_to_subclasses
-                                            .entry(c.0.clone())
-                                            .or_insert(HashSet::new())
-                                            .insert(d.0.clone());
-                                        let _ = &self
-                                            .classes_to_superclasses
-                                            .entry(d.0.clone())
-                                            .or_insert(HashSet::new())
-                                            .insert(c.0.clone());
-                                    }
-                                    _ => (),
+            _ => (),
+        }
+
+        for (sub, sup) in self.hierarchy_edges(&ax.axiom) {
+            self.add_hierarchy_edge(sub, sup);
+        }
+
+        for (sub, sup) in self.property_edges(&ax.axiom) {
+            closure::link(&mut self.properties_to_subproperties, sup.clone(), sub.clone());
+            closure::link(&mut self.properties_to_superproperties, sub, sup);
+        }
+
+        for (a, b) in self.inverse_pairs(&ax.axiom) {
+            closure::link(&mut self.properties_to_inverses, a, b);
+        }
+
+        for (sup, chain) in self.property_chains(&ax.axiom) {
+            self.properties_to_chains
+                .entry(sup)
+                .or_insert(HashSet::new())
+                .insert(chain);
+        }
+
+        for (class, p, filler) in self.relation_edges(&ax.axiom) {
+            closure::link(
+                self.classes_to_relations.entry(class).or_insert(HashMap::new()),
+                p,
+                filler,
+            );
+        }
+    }
+
+    /// Returns the (subclass, superclass) pairs of named classes asserted by an axiom.
+    ///
+    /// Which axioms contribute depends on the hierarchy mode of the ontology.
+    fn hierarchy_edges(&self, axiom: &Axiom<ArcStr>) -> Vec<(IRI<ArcStr>, IRI<ArcStr>)> {
+        let mut edges = Vec::new();
+
+        match axiom {
+            Axiom::SubClassOf(SubClassOf {
+                sup,
+                sub: ClassExpression::Class(sub),
+            }) => match sup {
+                ClassExpression::Class(sup) => edges.push((sub.0.clone(), sup.0.clone())),
+                ClassExpression::ObjectIntersectionOf(conjuncts)
+                    if self.hierarchy_mode == HierarchyMode::Equivalents =>
+                {
+                    for sup in named_classes(conjuncts) {
+                        edges.push((sub.0.clone(), sup.0.clone()));
+                    }
+                }
+                _ => (),
+            },
+            Axiom::EquivalentClasses(EquivalentClasses(ces))
+                if self.hierarchy_mode == HierarchyMode::Equivalents =>
+            {
+                for sub in named_classes(ces) {
+                    for ce in ces {
+                        match ce {
+                            ClassExpression::Class(sup) if sup != sub => {
+                                edges.push((sub.0.clone(), sup.0.clone()))
+                            }
+                            ClassExpression::ObjectIntersectionOf(conjuncts) => {
+                                for sup in named_classes(conjuncts) {
+                                    edges.push((sub.0.clone(), sup.0.clone()));
                                 }
                             }
                             _ => (),
                         }
                     }
-                    _ => (),
                 }
             }
             _ => (),
         }
+
+        edges
+    }
+
+    fn add_hierarchy_edge(&mut self, sub: IRI<ArcStr>, sup: IRI<ArcStr>) {
+        //Direct subclasses only
+        closure::link(&mut self.classes_to_subclasses, sup.clone(), sub.clone());
+        closure::link(&mut self.classes_to_superclasses, sub, sup);
+    }
+
+    fn remove_hierarchy_edge(&mut self, sub: &IRI<ArcStr>, sup: &IRI<ArcStr>) {
+        closure::unlink(&mut self.classes_to_subclasses, sup, sub);
+        closure::unlink(&mut self.classes_to_superclasses, sub, sup);
+    }
+
+    /// Returns the (subproperty, superproperty) pairs of named properties asserted by an axiom.
+    fn property_edges(&self, axiom: &Axiom<ArcStr>) -> Vec<(IRI<ArcStr>, IRI<ArcStr>)> {
+        match axiom {
+            Axiom::SubObjectPropertyOf(SubObjectPropertyOf {
+                sup: ObjectPropertyExpression::ObjectProperty(sup),
+                sub:
+                    SubObjectPropertyExpression::ObjectPropertyExpression(
+                        ObjectPropertyExpression::ObjectProperty(sub),
+                    ),
+            }) => vec![(sub.0.clone(), sup.0.clone())],
+            Axiom::SubDataPropertyOf(SubDataPropertyOf { sup, sub }) => {
+                vec![(sub.0.clone(), sup.0.clone())]
+            }
+            Axiom::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sup, sub }) => {
+                vec![(sub.0.clone(), sup.0.clone())]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the pairs of object properties declared inverse by an axiom, in both directions.
+    fn inverse_pairs(&self, axiom: &Axiom<ArcStr>) -> Vec<(IRI<ArcStr>, IRI<ArcStr>)> {
+        match axiom {
+            Axiom::InverseObjectProperties(InverseObjectProperties(a, b)) => {
+                vec![(a.0.clone(), b.0.clone()), (b.0.clone(), a.0.clone())]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the super property and the chain of named properties of a property chain axiom.
+    fn property_chains(&self, axiom: &Axiom<ArcStr>) -> Vec<(IRI<ArcStr>, Vec<IRI<ArcStr>>)> {
+        match axiom {
+            Axiom::SubObjectPropertyOf(SubObjectPropertyOf {
+                sup: ObjectPropertyExpression::ObjectProperty(sup),
+                sub: SubObjectPropertyExpression::ObjectPropertyChain(chain),
+            }) => {
+                let named: Vec<IRI<ArcStr>> = chain
+                    .iter()
+                    .filter_map(|ope| match ope {
+                        ObjectPropertyExpression::ObjectProperty(op) => Some(op.0.clone()),
+                        _ => None,
+                    })
+                    .collect();
+
+                //Chains with inverse properties cannot be expressed as a list of IRIs
+                if named.len() == chain.len() {
+                    vec![(sup.0.clone(), named)]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the (class, property, filler) triples of named classes related by an axiom.
+    ///
+    /// A class is related to the fillers of existential restrictions it is a subclass of, either
+    /// directly or as conjuncts of an intersection, and of those it is equivalent to.
+    fn relation_edges(&self, axiom: &Axiom<ArcStr>) -> Vec<(IRI<ArcStr>, IRI<ArcStr>, IRI<ArcStr>)> {
+        let mut edges = Vec::new();
+
+        match axiom {
+            Axiom::SubClassOf(SubClassOf {
+                sup,
+                sub: ClassExpression::Class(sub),
+            }) => {
+                for (p, filler) in existential_restrictions(sup) {
+                    edges.push((sub.0.clone(), p, filler));
+                }
+            }
+            Axiom::EquivalentClasses(EquivalentClasses(ces)) => {
+                for sub in named_classes(ces) {
+                    for ce in ces {
+                        for (p, filler) in existential_restrictions(ce) {
+                            edges.push((sub.0.clone(), p, filler));
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        edges
+    }
+
+    /// Checks whether an axiom mentioning `iri` still yields the index entry `entry`.
+    ///
+    /// Index entries may be asserted by several axioms, so they are only dropped once unsupported.
+    fn still_asserted<T: PartialEq>(
+        &self,
+        iri: &IRI<ArcStr>,
+        entry: &T,
+        entries: impl Fn(&Self, &Axiom<ArcStr>) -> Vec<T>,
+    ) -> bool {
+        self.ontology
+            .axiom_for_iri(iri)
+            .any(|aax| entries(self, &aax.axiom).contains(entry))
+    }
+
+    /// Rebuilds the class hierarchy indexes from all axioms, e.g. after the hierarchy mode changed.
+    fn rebuild_hierarchy(&mut self) {
+        self.classes_to_subclasses.clear();
+        self.classes_to_superclasses.clear();
+
+        let edges: Vec<(IRI<ArcStr>, IRI<ArcStr>)> = self
+            .ontology
+            .iter()
+            .flat_map(|aax| self.hierarchy_edges(&aax.axiom))
+            .collect();
+
+        for (sub, sup) in edges {
+            self.add_hierarchy_edge(sub, sup);
+        }
+    }
+
+    /// Finds the axioms referencing `iri` as subject if `subject` is set or as filler if `filler` is set.
+    fn referencing_axioms(
+        &self,
+        iri: &IRI<ArcStr>,
+        subject: bool,
+        filler: bool,
+    ) -> Vec<&AnnotatedAxiom<ArcStr>> {
+        self.ontology
+            .axiom_for_iri(iri)
+            .filter(|aax| {
+                let (as_subject, as_filler) = reference_roles(aax, iri);
+                (subject && as_subject) || (filler && as_filler)
+            })
+            .collect()
+    }
+
+    /// Finds the axioms equal to `ax` with any annotations.
+    fn annotated_variants(&self, ax: &Axiom<ArcStr>) -> Vec<AnnotatedAxiom<ArcStr>> {
+        let term = model::Axiom::from(ax).to_term();
+        let mut iris = Vec::new();
+        term.walk(&mut |t| {
+            if let model::Term::IRI(iri) = t {
+                iris.push(iri);
+            }
+        });
+
+        //Any IRI of the axiom narrows down the candidates, the kind is the fallback
+        match iris.first() {
+            Some(iri) => self
+                .ontology
+                .axiom_for_iri(iri)
+                .filter(|aax| &aax.axiom == ax)
+                .cloned()
+                .collect(),
+            None => self
+                .ontology
+                .axiom_for_kind(ax.kind())
+                .filter(|aax| &aax.axiom == ax)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Checks whether all classes along a cycle are asserted equivalent to their successor.
+    fn is_equivalence_cycle(&self, cycle: &[IRI<ArcStr>]) -> bool {
+        cycle.iter().enumerate().all(|(i, a)| {
+            let b = &cycle[(i + 1) % cycle.len()];
+
+            self.ontology.axiom_for_iri(a).any(|aax| match &aax.axiom {
+                Axiom::EquivalentClasses(EquivalentClasses(ces)) => {
+                    let named = named_classes(ces);
+                    named.iter().any(|c| &c.0 == a) && named.iter().any(|c| &c.0 == b)
+                }
+                _ => false,
+            })
+        })
     }
 
     fn from(iro: IRIMappedOntology<ArcStr, Arc<AnnotatedAxiom<ArcStr>>>) -> PyIndexedOntology {
@@ -528,6 +2404,239 @@ impl PyIndexedOntology {
     }
 }
 
+/// Returns the language tag of a language tagged literal.
+fn language_of(av: &AnnotationValue<ArcStr>) -> Option<&str> {
+    match av {
+        AnnotationValue::Literal(Literal::Language { lang, .. }) => Some(lang.as_str()),
+        _ => None,
+    }
+}
+
+/// The axiom kinds by the names of their model classes.
+const AXIOM_KINDS: [(&str, AxiomKind); 44] = [
+    ("OntologyAnnotation", AxiomKind::OntologyAnnotation),
+    ("Import", AxiomKind::Import),
+    ("DeclareClass", AxiomKind::DeclareClass),
+    ("DeclareObjectProperty", AxiomKind::DeclareObjectProperty),
+    ("DeclareAnnotationProperty", AxiomKind::DeclareAnnotationProperty),
+    ("DeclareDataProperty", AxiomKind::DeclareDataProperty),
+    ("DeclareNamedIndividual", AxiomKind::DeclareNamedIndividual),
+    ("DeclareDatatype", AxiomKind::DeclareDatatype),
+    ("SubClassOf", AxiomKind::SubClassOf),
+    ("EquivalentClasses", AxiomKind::EquivalentClasses),
+    ("DisjointClasses", AxiomKind::DisjointClasses),
+    ("DisjointUnion", AxiomKind::DisjointUnion),
+    ("SubObjectPropertyOf", AxiomKind::SubObjectPropertyOf),
+    ("EquivalentObjectProperties", AxiomKind::EquivalentObjectProperties),
+    ("DisjointObjectProperties", AxiomKind::DisjointObjectProperties),
+    ("InverseObjectProperties", AxiomKind::InverseObjectProperties),
+    ("ObjectPropertyDomain", AxiomKind::ObjectPropertyDomain),
+    ("ObjectPropertyRange", AxiomKind::ObjectPropertyRange),
+    ("FunctionalObjectProperty", AxiomKind::FunctionalObjectProperty),
+    ("InverseFunctionalObjectProperty", AxiomKind::InverseFunctionalObjectProperty),
+    ("ReflexiveObjectProperty", AxiomKind::ReflexiveObjectProperty),
+    ("IrreflexiveObjectProperty", AxiomKind::IrreflexiveObjectProperty),
+    ("SymmetricObjectProperty", AxiomKind::SymmetricObjectProperty),
+    ("AsymmetricObjectProperty", AxiomKind::AsymmetricObjectProperty),
+    ("TransitiveObjectProperty", AxiomKind::TransitiveObjectProperty),
+    ("SubDataPropertyOf", AxiomKind::SubDataPropertyOf),
+    ("EquivalentDataProperties", AxiomKind::EquivalentDataProperties),
+    ("DisjointDataProperties", AxiomKind::DisjointDataProperties),
+    ("DataPropertyDomain", AxiomKind::DataPropertyDomain),
+    ("DataPropertyRange", AxiomKind::DataPropertyRange),
+    ("FunctionalDataProperty", AxiomKind::FunctionalDataProperty),
+    ("DatatypeDefinition", AxiomKind::DatatypeDefinition),
+    ("HasKey", AxiomKind::HasKey),
+    ("SameIndividual", AxiomKind::SameIndividual),
+    ("DifferentIndividuals", AxiomKind::DifferentIndividuals),
+    ("ClassAssertion", AxiomKind::ClassAssertion),
+    ("ObjectPropertyAssertion", AxiomKind::ObjectPropertyAssertion),
+    (
+        "NegativeObjectPropertyAssertion",
+        AxiomKind::NegativeObjectPropertyAssertion,
+    ),
+    ("DataPropertyAssertion", AxiomKind::DataPropertyAssertion),
+    ("NegativeDataPropertyAssertion", AxiomKind::NegativeDataPropertyAssertion),
+    ("AnnotationAssertion", AxiomKind::AnnotationAssertion),
+    ("SubAnnotationPropertyOf", AxiomKind::SubAnnotationPropertyOf),
+    ("AnnotationPropertyDomain", AxiomKind::AnnotationPropertyDomain),
+    ("AnnotationPropertyRange", AxiomKind::AnnotationPropertyRange),
+];
+
+fn axiom_kind(name: &str) -> Option<AxiomKind> {
+    AXIOM_KINDS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, kind)| *kind)
+}
+
+/// The default columns of `to_table`.
+const TABLE_COLUMNS: [&str; 5] = ["iri", "curie", "label", "definition", "parents"];
+
+/// The predicate of class hierarchy edges in `to_edge_list`.
+const SUBCLASS_OF: &str = "subClassOf";
+
+const OBO_PURL: &str = "http://purl.obolibrary.org/obo/";
+
+/// Prefixes of OBO Foundry ontologies, whose CURIEs expand to OBO PURLs without a prefix mapping.
+const OBO_PREFIXES: [&str; 30] = [
+    "BFO", "CARO", "CHEBI", "CL", "CLO", "DOID", "ECO", "ENVO", "FOODON", "GO", "HP", "IAO",
+    "MONDO", "MP", "NCBITaxon", "NCIT", "OBI", "OGMS", "OMO", "PATO", "PCO", "PO", "PR", "RO",
+    "SO", "STATO", "UBERON", "UO", "VO", "ZFA",
+];
+const IAO_DEFINITION: &str = "http://purl.obolibrary.org/obo/IAO_0000115";
+const OWL_DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const IAO_REPLACED_BY: &str = "http://purl.obolibrary.org/obo/IAO_0100001";
+const IAO_OBSOLESCENCE_REASON: &str = "http://purl.obolibrary.org/obo/IAO_0000231";
+const OBO_CONSIDER: &str = "http://www.geneontology.org/formats/oboInOwl#consider";
+
+fn entity_into_py(py: Python, kind: &str, iri: IRI<ArcStr>) -> PyObject {
+    let iri = model::IRI::from(iri);
+
+    match kind {
+        "Class" => model::Class(iri).into_py(py),
+        "ObjectProperty" => model::ObjectProperty(iri).into_py(py),
+        "DataProperty" => model::DataProperty(iri).into_py(py),
+        "AnnotationProperty" => model::AnnotationProperty(iri).into_py(py),
+        "NamedIndividual" => model::NamedIndividual(iri).into_py(py),
+        _ => model::Datatype(iri).into_py(py),
+    }
+}
+
+/// The fields of an axiom holding the entities the axiom is about.
+fn subject_fields(axiom: &str) -> &'static [&'static str] {
+    match axiom {
+        "OntologyAnnotation" | "Import" => &[],
+        "SubClassOf" | "SubObjectPropertyOf" | "SubDataPropertyOf" | "SubAnnotationPropertyOf" => {
+            &["sub"]
+        }
+        "InverseObjectProperties" => &["first", "second"],
+        "ObjectPropertyDomain" | "ObjectPropertyRange" => &["ope"],
+        "DataPropertyDomain" | "DataPropertyRange" => &["dp"],
+        "AnnotationPropertyDomain" | "AnnotationPropertyRange" => &["ap"],
+        "DatatypeDefinition" => &["kind"],
+        "HasKey" => &["ce"],
+        "ClassAssertion" => &["i"],
+        "ObjectPropertyAssertion"
+        | "NegativeObjectPropertyAssertion"
+        | "DataPropertyAssertion"
+        | "NegativeDataPropertyAssertion" => &["from"],
+        "AnnotationAssertion" => &["subject"],
+        //Declarations, n-ary axioms and property characteristics
+        _ => &["first"],
+    }
+}
+
+/// Checks whether axioms of a kind are logical axioms, i.e. neither declarations nor annotations.
+fn is_logical(kind: AxiomKind) -> bool {
+    !matches!(
+        kind,
+        AxiomKind::OntologyAnnotation
+            | AxiomKind::Import
+            | AxiomKind::DeclareClass
+            | AxiomKind::DeclareObjectProperty
+            | AxiomKind::DeclareAnnotationProperty
+            | AxiomKind::DeclareDataProperty
+            | AxiomKind::DeclareNamedIndividual
+            | AxiomKind::DeclareDatatype
+            | AxiomKind::AnnotationAssertion
+            | AxiomKind::SubAnnotationPropertyOf
+            | AxiomKind::AnnotationPropertyDomain
+            | AxiomKind::AnnotationPropertyRange
+    )
+}
+
+/// Returns whether `iri` occurs in an axiom itself, leaving aside the annotations of the axiom.
+fn in_signature(axiom: &Axiom<ArcStr>, iri: &IRI<ArcStr>) -> bool {
+    let mut found = false;
+    model::Axiom::from(axiom).to_term().walk(&mut |t| {
+        if let model::Term::IRI(i) = t {
+            found |= i == iri;
+        }
+    });
+    found
+}
+
+/// Returns whether `iri` is a subject of `aax` and whether it occurs anywhere else in `aax`.
+fn reference_roles(aax: &AnnotatedAxiom<ArcStr>, iri: &IRI<ArcStr>) -> (bool, bool) {
+    fn occurrences(term: &model::Term, iri: &IRI<ArcStr>) -> usize {
+        let mut n = 0;
+        term.walk(&mut |t| {
+            if let model::Term::IRI(i) = t {
+                if i == iri {
+                    n += 1;
+                }
+            }
+        });
+        n
+    }
+
+    fn entity_occurrences(term: &model::Term, iri: &IRI<ArcStr>) -> usize {
+        match term {
+            model::Term::IRI(i) => (i == iri) as usize,
+            model::Term::Node(_, fields) => match fields.as_slice() {
+                [(_, model::Term::IRI(i))] => (i == iri) as usize,
+                _ => 0,
+            },
+            model::Term::List(terms) => terms.iter().map(|t| entity_occurrences(t, iri)).sum(),
+            _ => 0,
+        }
+    }
+
+    let axiom = model::Axiom::from(&aax.axiom).to_term();
+    let annotations = model::BTreeSetWrap::<model::Annotation>::from(&aax.ann).to_term();
+
+    let subject: usize = match &axiom {
+        model::Term::Node(kind, fields) => fields
+            .iter()
+            .filter(|(name, _)| subject_fields(kind).contains(name))
+            .map(|(_, t)| entity_occurrences(t, iri))
+            .sum(),
+        _ => 0,
+    };
+    let total = occurrences(&axiom, iri) + occurrences(&annotations, iri);
+
+    (subject > 0, total > subject)
+}
+
+fn named_classes(ces: &[ClassExpression<ArcStr>]) -> Vec<&Class<ArcStr>> {
+    ces.iter()
+        .filter_map(|ce| match ce {
+            ClassExpression::Class(c) => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the (property, filler) pairs of existential restrictions between named properties
+/// and classes in a class expression, which is such a restriction or an intersection of them.
+fn existential_restrictions(ce: &ClassExpression<ArcStr>) -> Vec<(IRI<ArcStr>, IRI<ArcStr>)> {
+    match ce {
+        ClassExpression::ObjectSomeValuesFrom {
+            ope: ObjectPropertyExpression::ObjectProperty(p),
+            bce,
+        } => match bce.as_ref() {
+            ClassExpression::Class(filler) => vec![(p.0.clone(), filler.0.clone())],
+            _ => vec![],
+        },
+        ClassExpression::ObjectIntersectionOf(conjuncts) => conjuncts
+            .iter()
+            .flat_map(existential_restrictions)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Quotes a field of a delimited table if it contains the delimiter, quotes or line breaks.
+fn table_field(value: &str, delimiter: char) -> String {
+    if value.contains(|c| c == delimiter || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 fn open_ontology_owx(
     ontology: &str,
     b: &Build<Arc<str>>,
@@ -545,6 +2654,16 @@ fn open_ontology_owx(
     r
 }
 
+fn open_ontology_obographs(ontology: &str, b: &Build<Arc<str>>) -> Result<ArcIRIMappedOntology, String> {
+    let document: serde_json::Value = if Path::new(&ontology).exists() {
+        let file = File::open(ontology).map_err(|e| e.to_string())?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| e.to_string())?
+    } else {
+        serde_json::from_str(ontology).map_err(|e| e.to_string())?
+    };
+    obographs::read(&document, b)
+}
+
 fn open_ontology_rdf(
     ontology: &str,
     b: &Build<Arc<str>>,
@@ -580,8 +2699,8 @@ fn open_ontology_rdf(
 /// 
 /// Opens an ontology from a path or plain text.
 /// 
-/// If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx or owl format.
-/// Note: Only .owl and .owx files are currently supported.
+/// If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx, owl or OBO Graphs JSON format.
+/// Note: Only .owl, .owx and .json (OBO Graphs) files are currently supported.
 #[pyfunction]
 fn open_ontology(ontology: &PyString) -> PyResult<PyIndexedOntology> {
     let ontology: String = ontology.extract().unwrap();
@@ -602,6 +2721,11 @@ fn open_ontology(ontology: &PyString) -> PyResult<PyIndexedOntology> {
         } else {
             Err(PyValueError::new_err("Unable to open ontology"))
         }
+    } else if ontology.ends_with("json") || ontology.trim_start().starts_with('{') {
+        match open_ontology_obographs(&ontology, &b) {
+            Ok(o) => Ok(PyIndexedOntology::from(o)),
+            Err(e) => Err(PyValueError::new_err(format!("Unable to open ontology: {}", e))),
+        }
     } else if ontology.ends_with("owl") {
         let r2 = open_ontology_rdf(&ontology, &b);
         if r2.is_ok() {
@@ -642,71 +2766,172 @@ fn open_ontology(ontology: &PyString) -> PyResult<PyIndexedOntology> {
     result
 }
 
-/// get_descendants(onto: PyIndexedOntology, parent: str) -> Set[str]
+/// get_descendants(onto: PyIndexedOntology, parent: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]
+/// 
+/// Gets all direct and indirect subclasses of a class.
 /// 
-/// Gets all direct and indirect subclasses of an class.
+/// `parent` itself is only included if `include_self` is true. Earlier versions always included it,
+/// pass `include_self=True` to keep that behaviour.
+/// If `max_depth` is given, only subclasses at most that many levels below `parent` are returned.
+/// Cycles in the class hierarchy do not stop the traversal but are reported with a `RuntimeWarning`.
 #[pyfunction]
-fn get_descendants(onto: &PyIndexedOntology, parent: &PyString) -> PyResult<HashSet<String>> {
-    let mut descendants = HashSet::new();
+#[pyo3(signature = (onto, parent, include_self=false, max_depth=None))]
+fn get_descendants(
+    py: Python,
+    onto: &PyIndexedOntology,
+    parent: &PyString,
+    include_self: bool,
+    max_depth: Option<usize>,
+) -> PyResult<HashSet<String>> {
     let parent: String = parent.extract().unwrap();
 
     let b = Build::new();
     let parentiri = b.iri(parent);
 
-    recurse_descendants(onto, &parentiri, &mut descendants);
+    let descendants = closure::closure(
+        &onto.classes_to_subclasses,
+        &parentiri,
+        include_self,
+        max_depth,
+    );
+    warn_cycles(py, onto, &descendants.cycles)?;
+
+    Ok(descendants.members.iter().map(|iri| iri.to_string()).collect())
+}
+
+/// get_ancestors(onto: PyIndexedOntology, child: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]
+/// 
+/// Gets all direct and indirect super classes of a class.
+/// 
+/// `child` itself is only included if `include_self` is true. Earlier versions always included it,
+/// pass `include_self=True` to keep that behaviour.
+/// If `max_depth` is given, only super classes at most that many levels above `child` are returned.
+/// Cycles in the class hierarchy do not stop the traversal but are reported with a `RuntimeWarning`.
+#[pyfunction]
+#[pyo3(signature = (onto, child, include_self=false, max_depth=None))]
+fn get_ancestors(
+    py: Python,
+    onto: &PyIndexedOntology,
+    child: &PyString,
+    include_self: bool,
+    max_depth: Option<usize>,
+) -> PyResult<HashSet<String>> {
+    let child: String = child.extract().unwrap();
 
-    Ok(descendants)
+    let b = Build::new();
+    let childiri = b.iri(child);
+
+    let ancestors = closure::closure(
+        &onto.classes_to_superclasses,
+        &childiri,
+        include_self,
+        max_depth,
+    );
+    warn_cycles(py, onto, &ancestors.cycles)?;
+
+    Ok(ancestors.members.iter().map(|iri| iri.to_string()).collect())
 }
 
-fn recurse_descendants(
+/// get_property_descendants(onto: PyIndexedOntology, parent: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]
+/// 
+/// Gets all direct and indirect sub properties of a property.
+/// 
+/// Behaves like `get_descendants` but over the property hierarchy.
+#[pyfunction]
+#[pyo3(signature = (onto, parent, include_self=false, max_depth=None))]
+fn get_property_descendants(
+    py: Python,
     onto: &PyIndexedOntology,
-    superclass: &IRI<ArcStr>,
-    descendants: &mut HashSet<String>,
-) {
-    descendants.insert(superclass.into());
-    if onto.classes_to_subclasses.contains_key(superclass) {
-        for cls2 in &mut onto.classes_to_subclasses[superclass].iter() {
-            recurse_descendants(onto, cls2, descendants);
-        }
-    }
+    parent: &PyString,
+    include_self: bool,
+    max_depth: Option<usize>,
+) -> PyResult<HashSet<String>> {
+    let parent: String = parent.extract().unwrap();
+
+    let b = Build::new();
+    let parentiri = b.iri(parent);
+
+    let descendants = closure::closure(
+        &onto.properties_to_subproperties,
+        &parentiri,
+        include_self,
+        max_depth,
+    );
+    warn_cycles(py, onto, &descendants.cycles)?;
+
+    Ok(descendants.members.iter().map(|iri| iri.to_string()).collect())
 }
 
-/// get_ancestors(onto: PyIndexedOntology, child: str) -> Set[str]
+/// get_property_ancestors(onto: PyIndexedOntology, child: str, include_self: bool = False, max_depth: Optional[int] = None) -> Set[str]
 /// 
-/// Gets all direct and indirect super classes of a class.
+/// Gets all direct and indirect super properties of a property.
+/// 
+/// Behaves like `get_ancestors` but over the property hierarchy.
 #[pyfunction]
-fn get_ancestors(onto: &PyIndexedOntology, child: &PyString) -> PyResult<HashSet<String>> {
-    let mut ancestors = HashSet::new();
+#[pyo3(signature = (onto, child, include_self=false, max_depth=None))]
+fn get_property_ancestors(
+    py: Python,
+    onto: &PyIndexedOntology,
+    child: &PyString,
+    include_self: bool,
+    max_depth: Option<usize>,
+) -> PyResult<HashSet<String>> {
     let child: String = child.extract().unwrap();
 
     let b = Build::new();
     let childiri = b.iri(child);
 
-    recurse_ancestors(onto, &childiri, &mut ancestors);
+    let ancestors = closure::closure(
+        &onto.properties_to_superproperties,
+        &childiri,
+        include_self,
+        max_depth,
+    );
+    warn_cycles(py, onto, &ancestors.cycles)?;
 
-    Ok(ancestors)
+    Ok(ancestors.members.iter().map(|iri| iri.to_string()).collect())
 }
 
-fn recurse_ancestors(
+fn warn_cycles(
+    py: Python,
     onto: &PyIndexedOntology,
-    subclass: &IRI<ArcStr>,
-    ancestors: &mut HashSet<String>,
-) {
-    ancestors.insert(subclass.into());
-    if onto.classes_to_superclasses.contains_key(subclass) {
-        for cls2 in &mut onto.classes_to_superclasses[subclass].iter() {
-            recurse_ancestors(onto, cls2, ancestors);
+    cycles: &[Vec<IRI<ArcStr>>],
+) -> PyResult<()> {
+    for cycle in cycles {
+        //Equivalent classes are parents of each other by design
+        if onto.hierarchy_mode == HierarchyMode::Equivalents && onto.is_equivalence_cycle(cycle) {
+            continue;
         }
+
+        let path: Vec<String> = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|iri| iri.to_string())
+            .collect();
+
+        PyErr::warn(
+            py,
+            py.get_type::<PyRuntimeWarning>(),
+            &format!("Cycle detected: {}", path.join(" -> ")),
+            1,
+        )?;
     }
+
+    Ok(())
 }
 
 #[pymodule]
 fn pyhornedowl(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIndexedOntology>()?;
+    m.add_class::<AxiomIterator>()?;
+    m.add_class::<query::Var>()?;
+    m.add_class::<query::Pattern>()?;
 
     m.add_function(wrap_pyfunction!(open_ontology, m)?)?;
     m.add_function(wrap_pyfunction!(get_descendants, m)?)?;
     m.add_function(wrap_pyfunction!(get_ancestors, m)?)?;
+    m.add_function(wrap_pyfunction!(get_property_descendants, m)?)?;
+    m.add_function(wrap_pyfunction!(get_property_ancestors, m)?)?;
 
     let model_sub_module = model::py_module(py)?;
     m.add_submodule(model_sub_module)?;
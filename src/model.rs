@@ -1,8 +1,13 @@
-use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::{hash_map::DefaultHasher, BTreeSet},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 
 use horned_owl::model::ArcStr;
 
-use pyo3::{exceptions::PyKeyError, prelude::*, types::{PyType, IntoPyDict}, PyObject};
+use pyo3::{exceptions::{PyKeyError, PyTypeError}, prelude::*, pyclass::CompareOp, types::{PyType, IntoPyDict, PyDict, PyList, PySet, PyTuple}, PyObject};
 
 use paste::paste;
 use regex::Regex;
@@ -209,7 +214,7 @@ macro_rules! wrapped_enum {
     }) => {
         paste! {
             #[allow(non_camel_case_types)]
-            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
             enum [<$name _ Inner>] {
                 $($(
                     $v_name([<$v_name_full>]),
@@ -219,7 +224,7 @@ macro_rules! wrapped_enum {
                 )?)*
             }
 
-            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
             pub struct $name([<$name _ Inner>]);
 
             impl ToPyi for $name {
@@ -270,7 +275,7 @@ macro_rules! wrapped_enum {
                     "\n\n",doc!($v_name_full))]
                 #[allow(non_camel_case_types)]
                 #[pyclass(module="pyhornedowl.model")]
-                #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+                #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
                 pub struct [<$v_name_full>]
                     $((
                         #[pyo3(get,set,name="first")]
@@ -303,6 +308,55 @@ macro_rules! wrapped_enum {
                         })?
                     }
 
+                    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                        op.matches(self.cmp(other))
+                    }
+
+                    fn __hash__(&self) -> u64 {
+                        hash_value(self)
+                    }
+
+                    fn __repr__(&self) -> String {
+                        self.to_term().repr()
+                    }
+
+                    fn __str__(&self) -> String {
+                        self.to_term().render(&|_| None, usize::MAX)
+                    }
+
+                    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, PyObject)> {
+                        let args: Vec<PyObject> = vec![
+                            $($(self.$field_s.clone().into_py(py),)*)?
+                            $(cond!(self.0.clone().into_py(py), $field_t0), $(cond!(self.1.clone().into_py(py), $field_t1),)?)?
+                        ];
+                        Ok((model_constructor(py, stringify!($v_name_full))?, PyTuple::new(py, args).into_py(py)))
+                    }
+
+                    fn __copy__(&self) -> Self {
+                        self.clone()
+                    }
+
+                    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                        self.clone()
+                    }
+
+                    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                        self.to_term().to_dict(py)
+                    }
+
+                    fn to_json(&self, py: Python<'_>) -> PyResult<String> {
+                        to_json(py, self.to_dict(py)?)
+                    }
+
+                    #[classmethod]
+                    fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                        <Self as FromDict>::from_dict(value)
+                    }
+
+                    #[classmethod]
+                    fn from_json(cls: &PyType, value: &str) -> PyResult<Self> {
+                        from_json(cls.py(), value)
+                    }
 
                     fn __getitem__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
                         match name {
@@ -355,13 +409,100 @@ macro_rules! wrapped_enum {
                             write!(&mut res, ", second: {}", to_py_type::<$field_t1>(String::new())).unwrap();
                         )?)?
                         write!(&mut res, "):\n        ...\n").unwrap();
+                        write!(&mut res, "{}", dict_pyi(stringify!($v_name_full))).unwrap();
                         write!(&mut res, "    ...\n").unwrap();
 
                         res
                     }
                 }
+
+                impl ToTerm for [<$v_name_full>] {
+                    fn to_term(&self) -> Term {
+                        Term::Node(stringify!($v_name_full), vec![
+                            $(
+                                ("first", cond!(self.0.to_term(), $field_t0)),
+                                $(("second", cond!(self.1.to_term(), $field_t1)),)?
+                            )?
+                            $($(
+                                (stringify!($field_s), self.$field_s.to_term()),
+                            )*)?
+                        ])
+                    }
+                }
+
+                impl MapIRIs for [<$v_name_full>] {
+                    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+                        $(
+                            cond!(self.0.map_iris(f), $field_t0);
+                            $(cond!(self.1.map_iris(f), $field_t1);)?
+                        )?
+                        $($(
+                            self.$field_s.map_iris(f);
+                        )*)?
+                    }
+                }
+
+                impl FromDict for [<$v_name_full>] {
+                    fn from_dict(value: &PyAny) -> PyResult<Self> {
+                        let fields = tagged_dict(value, stringify!($v_name_full))?;
+                        Ok([<$v_name_full>]
+                            $((
+                                cond!(FromDict::from_dict(dict_field(fields, "first")?)?, $field_t0),
+                                $(cond!(FromDict::from_dict(dict_field(fields, "second")?)?, $field_t1),)?
+                            ))?
+                            $({
+                                $($field_s: FromDict::from_dict(dict_field(fields, stringify!($field_s))?)?,)*
+                            })?
+                        )
+                    }
+                }
             )?)*
 
+            impl ToTerm for $name {
+                fn to_term(&self) -> Term {
+                    match &self.0 {
+                        $($(
+                            [<$name _ Inner>]::$v_name(val) => val.to_term(),
+                        )?)*
+                        $($(
+                            [<$name _ Inner>]::$v_name_transparent(val) => val.to_term(),
+                        )?)*
+                    }
+                }
+            }
+
+            impl MapIRIs for $name {
+                fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+                    match &mut self.0 {
+                        $($(
+                            [<$name _ Inner>]::$v_name(val) => val.map_iris(f),
+                        )?)*
+                        $($(
+                            [<$name _ Inner>]::$v_name_transparent(val) => val.map_iris(f),
+                        )?)*
+                    }
+                }
+            }
+
+            impl FromDict for $name {
+                fn from_dict(value: &PyAny) -> PyResult<Self> {
+                    let tag: String = dict_field(value.downcast()?, "type")?.extract()?;
+                    match tag.as_str() {
+                        $($(
+                            stringify!($v_name_full) => Ok($name([<$name _ Inner>]::$v_name(
+                                <[<$v_name_full>] as FromDict>::from_dict(value)?
+                            ))),
+                        )?)*
+                        $($(
+                            stringify!($v_name_transparent) => Ok($name([<$name _ Inner>]::$v_name_transparent(
+                                <$v_name_transparent as FromDict>::from_dict(value)?
+                            ))),
+                        )?)*
+                        _ => Err(PyTypeError::new_err(format!("A {} is not a {}.", tag, stringify!($name)))),
+                    }
+                }
+            }
+
             impl From<&horned_owl::model::$name<ArcStr>> for $name {
                 fn from(value: &horned_owl::model::$name<ArcStr>) -> Self {
                     match value {
@@ -385,6 +526,30 @@ macro_rules! wrapped_enum {
                     }
                 }
             }
+
+            impl WalkEntities for horned_owl::model::$name<ArcStr> {
+                fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+                    match self {
+                        $($(
+                            horned_owl::model::$name::$v_name_transparent::<ArcStr>(f0) => f0.walk_entities(None, f),
+                        )?)*
+                        $($($(
+                            horned_owl::model::$name::$v_name::<ArcStr>(f0 $(, cond!(f1, $field_t1))?) => {
+                                f0.walk_entities(entity_kind(stringify!($v_name_full)), f);
+                                $(cond!(f1, $field_t1).walk_entities(None, f);)?
+                            }
+                        )?)?)*
+                        $($($(
+                            horned_owl::model::$name::$v_name::<ArcStr>{
+                                $($field_s, )*
+                            } => {
+                                $($field_s.walk_entities(field_entity_kind(stringify!($field_s)), f);)*
+                            }
+                        )?)?)*
+                    }
+                }
+            }
+
             impl IntoPy<pyo3::PyObject> for $name {
                 fn into_py(self, py: pyo3::Python) -> pyo3::PyObject {
                     match self.0 {
@@ -471,7 +636,7 @@ macro_rules! wrapped {
                 doc!($name)
             )]
             #[pyclass(module="pyhornedowl.model",mapping)]
-            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
             pub struct $name {
                 $(
                     #[pyo3(get,set)]
@@ -488,6 +653,55 @@ macro_rules! wrapped {
                     }
                 }
 
+                fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                    op.matches(self.cmp(other))
+                }
+
+                fn __hash__(&self) -> u64 {
+                    hash_value(self)
+                }
+
+                fn __repr__(&self) -> String {
+                    self.to_term().repr()
+                }
+
+                fn __str__(&self) -> String {
+                    self.to_term().render(&|_| None, usize::MAX)
+                }
+
+                fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, PyObject)> {
+                    let args: Vec<PyObject> = vec![
+                        $(self.$field.clone().into_py(py),)*
+                    ];
+                    Ok((model_constructor(py, stringify!($name))?, PyTuple::new(py, args).into_py(py)))
+                }
+
+                fn __copy__(&self) -> Self {
+                    self.clone()
+                }
+
+                fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                    self.clone()
+                }
+
+                fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                    self.to_term().to_dict(py)
+                }
+
+                fn to_json(&self, py: Python<'_>) -> PyResult<String> {
+                    to_json(py, self.to_dict(py)?)
+                }
+
+                #[classmethod]
+                fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                    <Self as FromDict>::from_dict(value)
+                }
+
+                #[classmethod]
+                fn from_json(cls: &PyType, value: &str) -> PyResult<Self> {
+                    from_json(cls.py(), value)
+                }
+
                 fn __getitem__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
                     match name {
                         $(stringify!($field) => Ok(self.$field.clone().into_py(py)),)*
@@ -520,12 +734,36 @@ macro_rules! wrapped {
                         write!(&mut res, ", {}: {}", stringify!($field), to_py_type::<$type>(String::new())).unwrap();
                     )*
                     write!(&mut res, "):\n        ...\n").unwrap();
+                    write!(&mut res, "{}", dict_pyi(stringify!($name))).unwrap();
                     write!(&mut res, "    ...\n").unwrap();
 
                     res
                 }
             }
 
+            impl ToTerm for $name {
+                fn to_term(&self) -> Term {
+                    Term::Node(stringify!($name), vec![
+                        $((stringify!($field), self.$field.to_term()),)*
+                    ])
+                }
+            }
+
+            impl MapIRIs for $name {
+                fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+                    $(self.$field.map_iris(f);)*
+                }
+            }
+
+            impl FromDict for $name {
+                fn from_dict(value: &PyAny) -> PyResult<Self> {
+                    let fields = tagged_dict(value, stringify!($name))?;
+                    Ok($name {
+                        $($field: FromDict::from_dict(dict_field(fields, stringify!($field))?)?,)*
+                    })
+                }
+            }
+
             impl From<&horned_owl::model::$name<ArcStr>> for $name {
                 fn from(value: &horned_owl::model::$name<ArcStr>) -> Self {
                     $name {
@@ -543,6 +781,12 @@ macro_rules! wrapped {
                 }
             }
 
+            impl WalkEntities for horned_owl::model::$name<ArcStr> {
+                fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+                    $(self.$field.walk_entities(field_entity_kind(stringify!($field)), f);)*
+                }
+            }
+
             wrapped_base! {$name}
         }
 
@@ -559,7 +803,7 @@ macro_rules! wrapped {
             doc!($name)
         )]
         #[pyclass(module="pyhornedowl.model")]
-        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $name (
             #[pyo3(get,set,name="first")]
             pub $type0,
@@ -579,6 +823,56 @@ macro_rules! wrapped {
                 )
             }
 
+            fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                op.matches(self.cmp(other))
+            }
+
+            fn __hash__(&self) -> u64 {
+                hash_value(self)
+            }
+
+            fn __repr__(&self) -> String {
+                self.to_term().repr()
+            }
+
+            fn __str__(&self) -> String {
+                self.to_term().render(&|_| None, usize::MAX)
+            }
+
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, PyObject)> {
+                let args: Vec<PyObject> = vec![
+                    self.0.clone().into_py(py),
+                    $(cond!(self.1.clone().into_py(py), $type1),)?
+                ];
+                Ok((model_constructor(py, stringify!($name))?, PyTuple::new(py, args).into_py(py)))
+            }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                self.clone()
+            }
+
+            fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                self.to_term().to_dict(py)
+            }
+
+            fn to_json(&self, py: Python<'_>) -> PyResult<String> {
+                to_json(py, self.to_dict(py)?)
+            }
+
+            #[classmethod]
+            fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                <Self as FromDict>::from_dict(value)
+            }
+
+            #[classmethod]
+            fn from_json(cls: &PyType, value: &str) -> PyResult<Self> {
+                from_json(cls.py(), value)
+            }
+
             #[classmethod]
             fn __pyi__(_: &PyType) -> String {
                 let mut res = String::new();
@@ -595,12 +889,39 @@ macro_rules! wrapped {
                     write!(&mut res, ", second: {}", to_py_type::<$type1>(String::new())).unwrap();
                 )?
                 write!(&mut res, "):\n        ...\n").unwrap();
+                write!(&mut res, "{}", dict_pyi(stringify!($name))).unwrap();
                 write!(&mut res, "    ...\n").unwrap();
 
                 res
             }
         }
 
+        impl ToTerm for $name {
+            fn to_term(&self) -> Term {
+                Term::Node(stringify!($name), vec![
+                    ("first", self.0.to_term()),
+                    $(("second", cond!(self.1.to_term(), $type1)),)?
+                ])
+            }
+        }
+
+        impl MapIRIs for $name {
+            fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+                self.0.map_iris(f);
+                $(cond!(self.1.map_iris(f), $type1);)?
+            }
+        }
+
+        impl FromDict for $name {
+            fn from_dict(value: &PyAny) -> PyResult<Self> {
+                let fields = tagged_dict(value, stringify!($name))?;
+                Ok($name(
+                    FromDict::from_dict(dict_field(fields, "first")?)?,
+                    $(cond!(FromDict::from_dict(dict_field(fields, "second")?)?, $type1),)?
+                ))
+            }
+        }
+
         impl From<&horned_owl::model::$name<ArcStr>> for $name {
             fn from(value: &horned_owl::model::$name<ArcStr>) -> Self {
 
@@ -620,13 +941,20 @@ macro_rules! wrapped {
             }
         }
 
+        impl WalkEntities for horned_owl::model::$name<ArcStr> {
+            fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+                self.0.walk_entities(entity_kind(stringify!($name)), f);
+                $(cond!(self.1.walk_entities(None, f), $type1);)?
+            }
+        }
+
         wrapped_base! {$name}
 
     }};
     (transparent pub enum $name:ident {
         $($v_name:ident ( $field:ty ),)*
     }) => {
-        #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord)]
+        #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub enum $name {
             $(
                 #[pyo3(transparent)]
@@ -667,6 +995,37 @@ macro_rules! wrapped {
             }
         }
 
+        impl ToTerm for $name {
+            fn to_term(&self) -> Term {
+                match self {
+                    $($name::$v_name(inner) => inner.to_term(),)*
+                }
+            }
+        }
+
+        impl MapIRIs for $name {
+            fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+                match self {
+                    $($name::$v_name(inner) => inner.map_iris(f),)*
+                }
+            }
+        }
+
+        impl FromDict for $name {
+            fn from_dict(value: &PyAny) -> PyResult<Self> {
+                let py = value.py();
+                // Variants are told apart by their type, so only type errors move on to the next one
+                $(
+                    match <$field as FromDict>::from_dict(value) {
+                        Ok(inner) => return Ok($name::$v_name(inner)),
+                        Err(e) if e.is_instance_of::<PyTypeError>(py) => (),
+                        Err(e) => return Err(e),
+                    }
+                )*
+                Err(PyTypeError::new_err(format!("Cannot convert {} to a {}.", value.repr()?, stringify!($name))))
+            }
+        }
+
         impl From<&$name> for horned_owl::model::$name<ArcStr> {
             fn from(value: &$name) -> Self {
                 match value {
@@ -684,6 +1043,14 @@ macro_rules! wrapped {
             }
         }
 
+        impl WalkEntities for horned_owl::model::$name<ArcStr> {
+            fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+                match self {
+                    $(horned_owl::model::$name::$v_name(inner) => inner.walk_entities(None, f),)*
+                }
+            }
+        }
+
         wrapped_base! {$name}
     };
     (pub enum $name:ident {
@@ -843,11 +1210,621 @@ impl FromCompatible<BTreeSetWrap<Annotation>>
     }
 }
 
+/// The constructor of a model class as exported by `pyhornedowl.model`, which pickle requires
+/// to be the object found under the name of the class.
+fn model_constructor(py: Python<'_>, name: &str) -> PyResult<PyObject> {
+    Ok(py.import("pyhornedowl.model")?.getattr(name)?.into_py(py))
+}
+
+/// Hashes a model object for `__hash__`, consistent with its `Eq` implementation.
+fn hash_value<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 trait ToPyi {
     fn pyi(module: Option<String>) -> String;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// A generic tree view of a model object.
+///
+/// Allows to inspect model objects without matching on every model type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    /// A model object given by its class name and its named fields.
+    Node(&'static str, Vec<(&'static str, Term)>),
+    List(Vec<Term>),
+    Set(Vec<Term>),
+    IRI(horned_owl::model::IRI<ArcStr>),
+    String(String),
+    Int(u32),
+    Facet(Facet),
+}
+
+impl Term {
+    /// Calls `f` for this term and all terms nested in it, parents before children.
+    pub fn walk<'a, F: FnMut(&'a Term)>(&'a self, f: &mut F) {
+        f(self);
+        match self {
+            Term::Node(_, fields) => fields.iter().for_each(|(_, t)| t.walk(f)),
+            Term::List(terms) | Term::Set(terms) => terms.iter().for_each(|t| t.walk(f)),
+            _ => (),
+        }
+    }
+
+    /// Converts a model object to a term. Returns `None` if `ob` is not a model object.
+    pub fn from_py(ob: &PyAny) -> Option<Term> {
+        macro_rules! extract_term {
+            ($($t:ty),*) => {
+                $(
+                    if let Ok(value) = ob.extract::<$t>() {
+                        return Some(value.to_term());
+                    }
+                )*
+            };
+        }
+
+        extract_term!(
+            Axiom,
+            AnnotatedAxiom,
+            ClassExpression,
+            PropertyExpression,
+            DataRange,
+            Individual,
+            AnnotationValue,
+            Annotation,
+            FacetRestriction,
+            Facet
+        );
+
+        None
+    }
+
+    /// Converts a term back to a model object by calling the constructors of the model classes.
+    pub fn into_py(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(match self {
+            Term::Node(name, fields) => {
+                let args: Vec<PyObject> = fields
+                    .iter()
+                    .map(|(_, t)| t.into_py(py))
+                    .collect::<PyResult<_>>()?;
+                py.import("pyhornedowl.model")?
+                    .getattr(*name)?
+                    .call1(PyTuple::new(py, args))?
+                    .into_py(py)
+            }
+            Term::List(terms) => terms
+                .iter()
+                .map(|t| t.into_py(py))
+                .collect::<PyResult<Vec<PyObject>>>()?
+                .into_py(py),
+            Term::Set(terms) => {
+                let items = terms
+                    .iter()
+                    .map(|t| t.into_py(py))
+                    .collect::<PyResult<Vec<PyObject>>>()?;
+                PySet::new(py, &items)?.into_py(py)
+            }
+            Term::IRI(iri) => IRI(iri.clone()).into_py(py),
+            Term::String(s) => s.into_py(py),
+            Term::Int(n) => n.into_py(py),
+            Term::Facet(f) => f.clone().into_py(py),
+        })
+    }
+
+    /// Converts the term to JSON compatible values. Model objects become dicts with their class
+    /// name in the field `type`, IRIs and facets become strings, lists and sets become lists.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(match self {
+            Term::Node(name, fields) => {
+                let dict = PyDict::new(py);
+                dict.set_item("type", name)?;
+                for (field, t) in fields {
+                    dict.set_item(field, t.to_dict(py)?)?;
+                }
+                dict.into_py(py)
+            }
+            Term::List(terms) | Term::Set(terms) => terms
+                .iter()
+                .map(|t| t.to_dict(py))
+                .collect::<PyResult<Vec<PyObject>>>()?
+                .into_py(py),
+            Term::IRI(iri) => iri.to_string().into_py(py),
+            Term::String(s) => s.into_py(py),
+            Term::Int(n) => n.into_py(py),
+            Term::Facet(f) => format!("{:?}", f).into_py(py),
+        })
+    }
+
+    /// Renders the term as the Python constructor calls creating it.
+    pub fn repr(&self) -> String {
+        match self {
+            Term::Node(name, fields) => format!(
+                "{}({})",
+                name,
+                join(fields.iter().map(|(_, t)| t.repr()))
+            ),
+            Term::List(terms) => format!("[{}]", join(terms.iter().map(Term::repr))),
+            Term::Set(terms) if terms.is_empty() => "set()".to_string(),
+            Term::Set(terms) => format!("{{{}}}", join(terms.iter().map(Term::repr))),
+            Term::IRI(iri) => format!("IRI.parse({})", py_repr(&iri.to_string())),
+            Term::String(s) => py_repr(s),
+            Term::Int(n) => n.to_string(),
+            Term::Facet(f) => format!("Facet.{:?}", f),
+        }
+    }
+
+    /// Renders the term compactly. Entities are shown by the label returned by `label` or by
+    /// their IRI in angle brackets, literals in OWL functional syntax.
+    ///
+    /// Lines longer than `width` are broken between the arguments of nested terms.
+    pub fn render(
+        &self,
+        label: &dyn Fn(&horned_owl::model::IRI<ArcStr>) -> Option<String>,
+        width: usize,
+    ) -> String {
+        self.layout(label, width, 0)
+    }
+
+    fn layout(
+        &self,
+        label: &dyn Fn(&horned_owl::model::IRI<ArcStr>) -> Option<String>,
+        width: usize,
+        indent: usize,
+    ) -> String {
+        let flat = self.flat(label);
+        if indent + flat.len() <= width || self.atom(label).is_some() {
+            return flat;
+        }
+
+        let (open, children, close): (String, Vec<&Term>, &str) = match self {
+            Term::Node(name, fields) => (
+                format!("{}(", name),
+                fields.iter().map(|(_, t)| t).collect(),
+                ")",
+            ),
+            Term::List(terms) => ("[".to_string(), terms.iter().collect(), "]"),
+            Term::Set(terms) => ("{".to_string(), terms.iter().collect(), "}"),
+            _ => return flat,
+        };
+
+        let pad = " ".repeat(indent + 4);
+        let children: Vec<String> = children
+            .into_iter()
+            .map(|t| format!("{}{}", pad, t.layout(label, width, indent + 4)))
+            .collect();
+
+        format!(
+            "{}\n{}\n{}{}",
+            open,
+            children.join(",\n"),
+            " ".repeat(indent),
+            close
+        )
+    }
+
+    fn flat(&self, label: &dyn Fn(&horned_owl::model::IRI<ArcStr>) -> Option<String>) -> String {
+        if let Some(atom) = self.atom(label) {
+            return atom;
+        }
+
+        match self {
+            Term::Node(name, fields) => format!(
+                "{}({})",
+                name,
+                join(fields.iter().map(|(_, t)| t.flat(label)))
+            ),
+            Term::List(terms) => format!("[{}]", join(terms.iter().map(|t| t.flat(label)))),
+            Term::Set(terms) => format!("{{{}}}", join(terms.iter().map(|t| t.flat(label)))),
+            Term::String(s) => format!("{:?}", s),
+            Term::Int(n) => n.to_string(),
+            Term::Facet(f) => format!("{:?}", f),
+            Term::IRI(_) => unreachable!(),
+        }
+    }
+
+    /// The compact rendering of IRIs, entities and literals, which are never broken over lines.
+    fn atom(
+        &self,
+        label: &dyn Fn(&horned_owl::model::IRI<ArcStr>) -> Option<String>,
+    ) -> Option<String> {
+        let iri = |iri: &horned_owl::model::IRI<ArcStr>| match label(iri) {
+            Some(l) => format!("'{}'", l),
+            None => format!("<{}>", iri),
+        };
+
+        match self {
+            Term::IRI(i) => Some(iri(i)),
+            Term::Node(name, fields) => match (*name, fields.as_slice()) {
+                (_, [(_, Term::IRI(i))]) if ENTITY_KINDS.contains(name) => Some(iri(i)),
+                ("SimpleLiteral", [(_, Term::String(l))]) => Some(format!("{:?}", l)),
+                ("LanguageLiteral", [(_, Term::String(l)), (_, Term::String(lang))]) => {
+                    Some(format!("{:?}@{}", l, lang))
+                }
+                ("DatatypeLiteral", [(_, Term::String(l)), (_, Term::IRI(dt))]) => {
+                    Some(format!("{:?}^^{}", l, iri(dt)))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn join<I: Iterator<Item = String>>(items: I) -> String {
+    items.collect::<Vec<_>>().join(", ")
+}
+
+/// Quotes a string as Python's `repr` does, so that reprs can be pasted back into Python.
+fn py_repr(s: &str) -> String {
+    let quote = if s.contains('\'') && !s.contains('"') { '"' } else { '\'' };
+
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push(quote);
+    for c in s.chars() {
+        match c {
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c == quote => {
+                res.push('\\');
+                res.push(c);
+            }
+            c if c.is_control() || (c.is_whitespace() && c != ' ') => {
+                let n = c as u32;
+                if n < 0x100 {
+                    res.push_str(&format!("\\x{:02x}", n));
+                } else if n < 0x10000 {
+                    res.push_str(&format!("\\u{:04x}", n));
+                } else {
+                    res.push_str(&format!("\\U{:08x}", n));
+                }
+            }
+            c => res.push(c),
+        }
+    }
+    res.push(quote);
+
+    res
+}
+
+/// The model classes of named entities.
+pub const ENTITY_KINDS: [&str; 6] = [
+    "Class",
+    "ObjectProperty",
+    "DataProperty",
+    "AnnotationProperty",
+    "NamedIndividual",
+    "Datatype",
+];
+
+pub trait ToTerm {
+    fn to_term(&self) -> Term;
+}
+
+impl ToTerm for IRI {
+    fn to_term(&self) -> Term {
+        Term::IRI(self.0.clone())
+    }
+}
+
+impl ToTerm for StringWrapper {
+    fn to_term(&self) -> Term {
+        Term::String(self.0.clone())
+    }
+}
+
+impl ToTerm for String {
+    fn to_term(&self) -> Term {
+        Term::String(self.clone())
+    }
+}
+
+impl ToTerm for u32 {
+    fn to_term(&self) -> Term {
+        Term::Int(*self)
+    }
+}
+
+impl ToTerm for Facet {
+    fn to_term(&self) -> Term {
+        Term::Facet(self.clone())
+    }
+}
+
+impl<T: ToTerm> ToTerm for VecWrap<T> {
+    fn to_term(&self) -> Term {
+        Term::List(self.0.iter().map(ToTerm::to_term).collect())
+    }
+}
+
+impl<T: ToTerm> ToTerm for BoxWrap<T> {
+    fn to_term(&self) -> Term {
+        self.0.to_term()
+    }
+}
+
+impl<T: ToTerm> ToTerm for BTreeSetWrap<T> {
+    fn to_term(&self) -> Term {
+        Term::Set(self.0.iter().map(ToTerm::to_term).collect())
+    }
+}
+
+/// A callback for the kind and IRI of named entities.
+pub type EntityFn<'a> = dyn FnMut(&'static str, &horned_owl::model::IRI<ArcStr>) + 'a;
+
+/// Finds the named entities in horned-owl model objects without converting them.
+pub trait WalkEntities {
+    /// Calls `f` with the kind and IRI of every named entity in this object.
+    ///
+    /// `kind` is the kind of the entity named by this object if it is an IRI in an entity position.
+    fn walk_entities(&self, kind: Option<&'static str>, f: &mut EntityFn<'_>);
+}
+
+/// The kind of a model class if it is a named entity.
+fn entity_kind(name: &'static str) -> Option<&'static str> {
+    ENTITY_KINDS.contains(&name).then_some(name)
+}
+
+/// The kind of the entity named by an IRI in a field, as for the datatype of a literal.
+fn field_entity_kind(field: &'static str) -> Option<&'static str> {
+    (field == "datatype_iri").then_some("Datatype")
+}
+
+impl WalkEntities for horned_owl::model::IRI<ArcStr> {
+    fn walk_entities(&self, kind: Option<&'static str>, f: &mut EntityFn<'_>) {
+        if let Some(kind) = kind {
+            f(kind, self)
+        }
+    }
+
+    /// Converts a model object to a term. Returns `None` if `ob` is not a model object.
+    pub fn from_py(ob: &PyAny) -> Option<Term> {
+        macro_rules! extract_term {
+            ($($t:ty),*) => {
+                $(
+                    if let Ok(value) = ob.extract::<$t>() {
+                        return Some(value.to_term());
+                    }
+                )*
+            };
+        }
+
+        extract_term!(
+            Axiom,
+            AnnotatedAxiom,
+            ClassExpression,
+            PropertyExpression,
+            DataRange,
+            Individual,
+            AnnotationValue,
+            Annotation,
+            FacetRestriction,
+            Facet
+        );
+
+        None
+    }
+
+    /// Converts a term back to a model object by calling the constructors of the model classes.
+    pub fn into_py(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(match self {
+            Term::Node(name, fields) => {
+                let args: Vec<PyObject> = fields
+                    .iter()
+                    .map(|(_, t)| t.into_py(py))
+                    .collect::<PyResult<_>>()?;
+                py.import("pyhornedowl.model")?
+                    .getattr(*name)?
+                    .call1(PyTuple::new(py, args))?
+                    .into_py(py)
+            }
+            Term::List(terms) => terms
+                .iter()
+                .map(|t| t.into_py(py))
+                .collect::<PyResult<Vec<PyObject>>>()?
+                .into_py(py),
+            Term::Set(terms) => {
+                let items = terms
+                    .iter()
+                    .map(|t| t.into_py(py))
+                    .collect::<PyResult<Vec<PyObject>>>()?;
+                PySet::new(py, &items)?.into_py(py)
+            }
+            Term::IRI(iri) => IRI(iri.clone()).into_py(py),
+            Term::String(s) => s.into_py(py),
+            Term::Int(n) => n.into_py(py),
+            Term::Facet(f) => f.clone().into_py(py),
+        })
+    }
+}
+
+impl WalkEntities for ArcStr {
+    fn walk_entities(&self, _: Option<&'static str>, _: &mut EntityFn<'_>) {}
+}
+
+impl WalkEntities for String {
+    fn walk_entities(&self, _: Option<&'static str>, _: &mut EntityFn<'_>) {}
+}
+
+impl WalkEntities for u32 {
+    fn walk_entities(&self, _: Option<&'static str>, _: &mut EntityFn<'_>) {}
+}
+
+impl WalkEntities for horned_owl::model::Facet {
+    fn walk_entities(&self, _: Option<&'static str>, _: &mut EntityFn<'_>) {}
+}
+
+impl<T: WalkEntities> WalkEntities for Vec<T> {
+    fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+        self.iter().for_each(|t| t.walk_entities(None, f))
+    }
+}
+
+impl<T: WalkEntities> WalkEntities for Box<T> {
+    fn walk_entities(&self, kind: Option<&'static str>, f: &mut EntityFn<'_>) {
+        (**self).walk_entities(kind, f)
+    }
+}
+
+impl<T: WalkEntities> WalkEntities for BTreeSet<T> {
+    fn walk_entities(&self, _: Option<&'static str>, f: &mut EntityFn<'_>) {
+        self.iter().for_each(|t| t.walk_entities(None, f))
+    }
+}
+
+/// Replaces the IRIs in model objects.
+pub trait MapIRIs {
+    /// Calls `f` with every IRI in this model object, which may replace it.
+    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>));
+}
+
+impl MapIRIs for IRI {
+    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+        f(&mut self.0)
+    }
+}
+
+impl MapIRIs for StringWrapper {
+    fn map_iris(&mut self, _: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {}
+}
+
+impl MapIRIs for String {
+    fn map_iris(&mut self, _: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {}
+}
+
+impl MapIRIs for u32 {
+    fn map_iris(&mut self, _: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {}
+}
+
+impl MapIRIs for Facet {
+    fn map_iris(&mut self, _: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {}
+}
+
+impl<T: MapIRIs> MapIRIs for VecWrap<T> {
+    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+        self.0.iter_mut().for_each(|t| t.map_iris(f))
+    }
+}
+
+impl<T: MapIRIs> MapIRIs for BoxWrap<T> {
+    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+        self.0.map_iris(f)
+    }
+}
+
+impl<T: MapIRIs + Ord> MapIRIs for BTreeSetWrap<T> {
+    fn map_iris(&mut self, f: &mut dyn FnMut(&mut horned_owl::model::IRI<ArcStr>)) {
+        //The order of the elements may change, so the set is rebuilt
+        self.0 = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(|mut t| {
+                t.map_iris(f);
+                t
+            })
+            .collect();
+    }
+}
+
+/// Conversion from the JSON compatible values produced by `Term::to_dict`.
+pub trait FromDict: Sized {
+    fn from_dict(value: &PyAny) -> PyResult<Self>;
+}
+
+impl FromDict for IRI {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        let builder = horned_owl::model::Build::new_arc();
+        Ok(IRI(builder.iri(value.extract::<String>()?)))
+    }
+}
+
+impl FromDict for StringWrapper {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        value.extract()
+    }
+}
+
+impl FromDict for String {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        value.extract()
+    }
+}
+
+impl FromDict for u32 {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        value.extract()
+    }
+}
+
+impl FromDict for Facet {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        value
+            .py()
+            .get_type::<Facet>()
+            .getattr(value.extract::<&str>()?)?
+            .extract()
+    }
+}
+
+impl<T: FromDict> FromDict for VecWrap<T> {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        let items = value.downcast::<PyList>()?;
+        Ok(VecWrap(items.iter().map(T::from_dict).collect::<PyResult<_>>()?))
+    }
+}
+
+impl<T: FromDict> FromDict for BoxWrap<T> {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        Ok(BoxWrap(Box::new(T::from_dict(value)?)))
+    }
+}
+
+impl<T: FromDict + Ord> FromDict for BTreeSetWrap<T> {
+    fn from_dict(value: &PyAny) -> PyResult<Self> {
+        let items = value.downcast::<PyList>()?;
+        Ok(BTreeSetWrap(items.iter().map(T::from_dict).collect::<PyResult<_>>()?))
+    }
+}
+
+/// Returns `value` as dict after checking that its `type` is `name`.
+fn tagged_dict<'a>(value: &'a PyAny, name: &str) -> PyResult<&'a PyDict> {
+    let fields = value.downcast::<PyDict>()?;
+    let tag: String = dict_field(fields, "type")?.extract()?;
+    if tag == name {
+        Ok(fields)
+    } else {
+        Err(PyTypeError::new_err(format!("A {} is not a {}.", tag, name)))
+    }
+}
+
+fn dict_field<'a>(fields: &'a PyDict, name: &str) -> PyResult<&'a PyAny> {
+    fields
+        .get_item(name)?
+        .ok_or_else(|| PyKeyError::new_err(format!("The field '{}' is missing.", name)))
+}
+
+fn to_json(py: Python<'_>, value: PyObject) -> PyResult<String> {
+    py.import("json")?.call_method1("dumps", (value,))?.extract()
+}
+
+fn from_json<T: FromDict>(py: Python<'_>, value: &str) -> PyResult<T> {
+    T::from_dict(py.import("json")?.call_method1("loads", (value,))?)
+}
+
+fn dict_pyi(name: &str) -> String {
+    let mut res = String::new();
+
+    write!(&mut res, "    def to_dict(self) -> dict:\n        ...\n").unwrap();
+    write!(&mut res, "    def to_json(self) -> str:\n        ...\n").unwrap();
+    write!(&mut res, "    @classmethod\n    def from_dict(cls, value: dict) -> {}:\n        ...\n", name).unwrap();
+    write!(&mut res, "    @classmethod\n    def from_json(cls, value: str) -> {}:\n        ...\n", name).unwrap();
+
+    res
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct VecWrap<T>(Vec<T>);
 
 impl<T> From<Vec<T>> for VecWrap<T> {
@@ -874,7 +1851,7 @@ impl<T: IntoPy<pyo3::PyObject>> IntoPy<pyo3::PyObject> for VecWrap<T> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BoxWrap<T>(Box<T>);
 
 impl<'source, T: FromPyObject<'source>> FromPyObject<'source> for BoxWrap<T> {
@@ -889,7 +1866,7 @@ impl<T: IntoPy<pyo3::PyObject>> IntoPy<pyo3::PyObject> for BoxWrap<T> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[pyclass(module = "pyhornedowl.model")]
 pub struct IRI(horned_owl::model::IRI<ArcStr>);
 
@@ -920,12 +1897,32 @@ impl From<&horned_owl::model::IRI<ArcStr>> for IRI {
 #[pymethods]
 impl IRI {
     pub fn __repr__(&self) -> String {
-        format!("IRI.parse(\"{}\")", self.0)
+        format!("IRI.parse({})", py_repr(&self.0.to_string()))
     }
     pub fn __str__(&self) -> String {
         self.0.to_string()
     }
 
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(other))
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_value(self)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        Ok((py.get_type::<Self>().getattr("parse")?.into_py(py), (self.0.to_string(),)))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
     #[classmethod]
     pub fn parse(_: &PyType, value: String) -> Self {
         let builder = horned_owl::model::Build::new_arc();
@@ -939,7 +1936,7 @@ impl IRI {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StringWrapper(String);
 
 impl From<&Arc<str>> for StringWrapper {
@@ -968,7 +1965,7 @@ impl<'source> FromPyObject<'source> for StringWrapper {
 
 
 #[doc = doc!(Facet)]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[pyclass(module = "pyhornedowl.model")]
 pub enum Facet {
     Length = 1,
@@ -986,6 +1983,22 @@ pub enum Facet {
 
 #[pymethods]
 impl Facet {
+    /// Facets are restored by their name, as enum members cannot be constructed.
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (PyObject, String))> {
+        Ok((
+            py.import("builtins")?.getattr("getattr")?.into_py(py),
+            (py.get_type::<Self>().into_py(py), format!("{:?}", self)),
+        ))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
     #[classmethod]
     fn __pyi__(_: &PyType) -> String {
         "class Facet:
@@ -1485,7 +2498,7 @@ wrapped! {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BTreeSetWrap<T>(BTreeSet<T>);
 
 impl<T> From<BTreeSet<T>> for BTreeSetWrap<T> {
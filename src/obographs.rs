@@ -0,0 +1,648 @@
+use horned_owl::model::{
+    AnnotatedAxiom, Annotation, AnnotationAssertion, AnnotationSubject, AnnotationValue, ArcStr,
+    Axiom, Build, ClassAssertion, ClassExpression, DeclareAnnotationProperty, DeclareClass,
+    DeclareDataProperty, DeclareNamedIndividual, DeclareObjectProperty, EquivalentClasses,
+    Individual, InverseObjectProperties, Literal, MutableOntology, ObjectPropertyAssertion,
+    ObjectPropertyDomain, ObjectPropertyExpression, ObjectPropertyRange, Ontology,
+    OntologyAnnotation, OntologyID, SubAnnotationPropertyOf, SubClassOf, SubDataPropertyOf,
+    SubObjectPropertyExpression, SubObjectPropertyOf, IRI,
+};
+use horned_owl::ontology::iri_mapped::ArcIRIMappedOntology;
+
+use serde_json::{json, Value};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+const RDFS_COMMENT: &str = "http://www.w3.org/2000/01/rdf-schema#comment";
+const OWL_DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const IAO_DEFINITION: &str = "http://purl.obolibrary.org/obo/IAO_0000115";
+const OBO_IN_OWL: &str = "http://www.geneontology.org/formats/oboInOwl#";
+const HAS_DB_XREF: &str = "http://www.geneontology.org/formats/oboInOwl#hasDbXref";
+const IN_SUBSET: &str = "http://www.geneontology.org/formats/oboInOwl#inSubset";
+const HAS_SYNONYM_TYPE: &str = "http://www.geneontology.org/formats/oboInOwl#hasSynonymType";
+
+const SYNONYMS: [&str; 4] = [
+    "hasExactSynonym",
+    "hasNarrowSynonym",
+    "hasBroadSynonym",
+    "hasRelatedSynonym",
+];
+
+/// Converts an ontology to an OBO Graphs JSON document with a single graph.
+///
+/// Axioms without an OBO Graphs counterpart, e.g. subclass axioms with nested class expressions,
+/// are left out.
+pub fn write(ontology: &ArcIRIMappedOntology) -> Value {
+    let mut writer = Writer::default();
+    for aax in ontology.iter() {
+        writer.axiom(aax);
+    }
+
+    writer.graph(ontology.id())
+}
+
+/// Reads all graphs of an OBO Graphs JSON document into a single ontology.
+///
+/// The ontology IRI and version IRI are taken from the first graph.
+pub fn read(document: &Value, b: &Build<ArcStr>) -> Result<ArcIRIMappedOntology, String> {
+    let graphs = document["graphs"]
+        .as_array()
+        .ok_or("Expected an OBO Graphs document with a 'graphs' list")?;
+
+    let mut reader = Reader {
+        b,
+        ontology: ArcIRIMappedOntology::new_arc(),
+        kinds: HashMap::new(),
+    };
+    for (i, graph) in graphs.iter().enumerate() {
+        reader.graph(graph, i == 0)?;
+    }
+
+    Ok(reader.ontology)
+}
+
+#[derive(Default)]
+struct Writer {
+    meta: Value,
+    nodes: BTreeMap<String, Value>,
+    edges: Vec<Value>,
+    equivalent_nodes_sets: Vec<Value>,
+    logical_definition_axioms: Vec<Value>,
+    domain_range_axioms: BTreeMap<String, Value>,
+    property_chain_axioms: Vec<Value>,
+}
+
+impl Writer {
+    fn graph(mut self, id: &OntologyID<ArcStr>) -> Value {
+        //Sorted like the nodes, so that saving the same ontology gives the same document
+        for values in [
+            &mut self.edges,
+            &mut self.equivalent_nodes_sets,
+            &mut self.logical_definition_axioms,
+            &mut self.property_chain_axioms,
+        ] {
+            values.sort_by_cached_key(|value| value.to_string());
+        }
+
+        let mut graph = json!({
+            "nodes": self.nodes.into_values().collect::<Vec<_>>(),
+            "edges": self.edges,
+            "equivalentNodesSets": self.equivalent_nodes_sets,
+            "logicalDefinitionAxioms": self.logical_definition_axioms,
+            "domainRangeAxioms": self.domain_range_axioms.into_values().collect::<Vec<_>>(),
+            "propertyChainAxioms": self.property_chain_axioms,
+        });
+
+        let mut meta = self.meta;
+        if let Some(iri) = &id.iri {
+            graph["id"] = iri.to_string().into();
+        }
+        if let Some(viri) = &id.viri {
+            meta["version"] = viri.to_string().into();
+        }
+        if !meta.is_null() {
+            graph["meta"] = meta;
+        }
+
+        json!({ "graphs": [graph] })
+    }
+
+    fn node(&mut self, id: &IRI<ArcStr>) -> &mut Value {
+        self.nodes
+            .entry(id.to_string())
+            .or_insert_with(|| json!({ "id": id.to_string() }))
+    }
+
+    fn declare(&mut self, id: &IRI<ArcStr>, kind: &str, property_type: Option<&str>) {
+        let node = self.node(id);
+        node["type"] = kind.into();
+        if let Some(property_type) = property_type {
+            node["propertyType"] = property_type.into();
+        }
+    }
+
+    fn edge(&mut self, sub: &IRI<ArcStr>, pred: &str, obj: &IRI<ArcStr>) {
+        self.edges.push(json!({
+            "sub": sub.to_string(),
+            "pred": pred,
+            "obj": obj.to_string(),
+        }));
+    }
+
+    fn domain_range(&mut self, property: &IRI<ArcStr>) -> &mut Value {
+        self.domain_range_axioms
+            .entry(property.to_string())
+            .or_insert_with(|| json!({ "predicateId": property.to_string() }))
+    }
+
+    fn axiom(&mut self, aax: &AnnotatedAxiom<ArcStr>) {
+        match &aax.axiom {
+            Axiom::DeclareClass(DeclareClass(c)) => self.declare(&c.0, "CLASS", None),
+            Axiom::DeclareObjectProperty(DeclareObjectProperty(p)) => {
+                self.declare(&p.0, "PROPERTY", Some("OBJECT"))
+            }
+            Axiom::DeclareAnnotationProperty(DeclareAnnotationProperty(p)) => {
+                self.declare(&p.0, "PROPERTY", Some("ANNOTATION"))
+            }
+            Axiom::DeclareDataProperty(DeclareDataProperty(p)) => {
+                self.declare(&p.0, "PROPERTY", Some("DATA"))
+            }
+            Axiom::DeclareNamedIndividual(DeclareNamedIndividual(i)) => {
+                self.declare(&i.0, "INDIVIDUAL", None)
+            }
+            Axiom::OntologyAnnotation(OntologyAnnotation(ann)) => {
+                push(&mut self.meta["basicPropertyValues"], property_value(ann))
+            }
+            Axiom::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann,
+            }) => self.annotation(subject, ann, &aax.ann),
+            Axiom::SubClassOf(SubClassOf {
+                sub: ClassExpression::Class(sub),
+                sup,
+            }) => match sup {
+                ClassExpression::Class(sup) => self.edge(&sub.0, "is_a", &sup.0),
+                ClassExpression::ObjectSomeValuesFrom {
+                    ope: ObjectPropertyExpression::ObjectProperty(p),
+                    bce,
+                } => {
+                    if let ClassExpression::Class(filler) = bce.as_ref() {
+                        self.edge(&sub.0, &p.0.to_string(), &filler.0)
+                    }
+                }
+                _ => (),
+            },
+            Axiom::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub,
+                sup: ObjectPropertyExpression::ObjectProperty(sup),
+            }) => match sub {
+                SubObjectPropertyExpression::ObjectPropertyExpression(
+                    ObjectPropertyExpression::ObjectProperty(sub),
+                ) => self.edge(&sub.0, "subPropertyOf", &sup.0),
+                SubObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                    let ids: Option<Vec<String>> = chain
+                        .iter()
+                        .map(|ope| match ope {
+                            ObjectPropertyExpression::ObjectProperty(p) => Some(p.0.to_string()),
+                            _ => None,
+                        })
+                        .collect();
+                    if let Some(ids) = ids {
+                        self.property_chain_axioms.push(json!({
+                            "predicateId": sup.0.to_string(),
+                            "chainPredicateIds": ids,
+                        }));
+                    }
+                }
+                _ => (),
+            },
+            Axiom::SubDataPropertyOf(SubDataPropertyOf { sub, sup }) => {
+                self.edge(&sub.0, "subPropertyOf", &sup.0)
+            }
+            Axiom::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, sup }) => {
+                self.edge(&sub.0, "subPropertyOf", &sup.0)
+            }
+            Axiom::InverseObjectProperties(InverseObjectProperties(a, b)) => {
+                self.edge(&a.0, "inverseOf", &b.0)
+            }
+            Axiom::ClassAssertion(ClassAssertion {
+                ce: ClassExpression::Class(c),
+                i: Individual::Named(i),
+            }) => self.edge(&i.0, "type", &c.0),
+            Axiom::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                ope: ObjectPropertyExpression::ObjectProperty(p),
+                from: Individual::Named(from),
+                to: Individual::Named(to),
+            }) => self.edge(&from.0, &p.0.to_string(), &to.0),
+            Axiom::EquivalentClasses(EquivalentClasses(ces)) => self.equivalent_classes(ces),
+            Axiom::ObjectPropertyDomain(ObjectPropertyDomain {
+                ope: ObjectPropertyExpression::ObjectProperty(p),
+                ce: ClassExpression::Class(c),
+            }) => push(
+                &mut self.domain_range(&p.0)["domainClassIds"],
+                c.0.to_string().into(),
+            ),
+            Axiom::ObjectPropertyRange(ObjectPropertyRange {
+                ope: ObjectPropertyExpression::ObjectProperty(p),
+                ce: ClassExpression::Class(c),
+            }) => push(
+                &mut self.domain_range(&p.0)["rangeClassIds"],
+                c.0.to_string().into(),
+            ),
+            _ => (),
+        }
+    }
+
+    fn annotation(
+        &mut self,
+        subject: &IRI<ArcStr>,
+        ann: &Annotation<ArcStr>,
+        axiom_anns: &BTreeSet<Annotation<ArcStr>>,
+    ) {
+        let ap = ann.ap.0.to_string();
+        let val = value_string(&ann.av);
+        let xrefs: Vec<String> = axiom_anns
+            .iter()
+            .filter(|a| a.ap.0.to_string() == HAS_DB_XREF)
+            .map(|a| value_string(&a.av))
+            .collect();
+
+        let node = self.node(subject);
+        match ap.as_str() {
+            RDFS_LABEL => {
+                if node["lbl"].is_null() {
+                    node["lbl"] = val.into();
+                }
+            }
+            IAO_DEFINITION => node["meta"]["definition"] = json!({ "val": val, "xrefs": xrefs }),
+            RDFS_COMMENT => push(&mut node["meta"]["comments"], val.into()),
+            IN_SUBSET => push(&mut node["meta"]["subsets"], val.into()),
+            HAS_DB_XREF => push(&mut node["meta"]["xrefs"], json!({ "val": val })),
+            OWL_DEPRECATED => node["meta"]["deprecated"] = (val == "true").into(),
+            _ => match ap.strip_prefix(OBO_IN_OWL).filter(|p| SYNONYMS.contains(p)) {
+                Some(pred) => {
+                    let mut synonym = json!({ "pred": pred, "val": val, "xrefs": xrefs });
+                    if let Some(t) = axiom_anns
+                        .iter()
+                        .find(|a| a.ap.0.to_string() == HAS_SYNONYM_TYPE)
+                    {
+                        synonym["synonymType"] = value_string(&t.av).into();
+                    }
+                    push(&mut node["meta"]["synonyms"], synonym);
+                }
+                None => push(
+                    &mut node["meta"]["basicPropertyValues"],
+                    property_value(ann),
+                ),
+            },
+        }
+    }
+
+    /// Named classes become an equivalent node set, a named class equivalent to an intersection
+    /// of named classes and existential restrictions a logical definition.
+    fn equivalent_classes(&mut self, ces: &[ClassExpression<ArcStr>]) {
+        let named: Option<Vec<String>> = ces
+            .iter()
+            .map(|ce| match ce {
+                ClassExpression::Class(c) => Some(c.0.to_string()),
+                _ => None,
+            })
+            .collect();
+        if let Some(ids) = named {
+            self.equivalent_nodes_sets.push(json!({ "nodeIds": ids }));
+            return;
+        }
+
+        if let [ClassExpression::Class(defined), ClassExpression::ObjectIntersectionOf(operands)]
+        | [ClassExpression::ObjectIntersectionOf(operands), ClassExpression::Class(defined)] = ces
+        {
+            let mut genus_ids = Vec::new();
+            let mut restrictions = Vec::new();
+            for operand in operands {
+                match operand {
+                    ClassExpression::Class(c) => genus_ids.push(c.0.to_string()),
+                    ClassExpression::ObjectSomeValuesFrom {
+                        ope: ObjectPropertyExpression::ObjectProperty(p),
+                        bce,
+                    } => match bce.as_ref() {
+                        ClassExpression::Class(filler) => restrictions.push(json!({
+                            "propertyId": p.0.to_string(),
+                            "fillerId": filler.0.to_string(),
+                        })),
+                        _ => return,
+                    },
+                    _ => return,
+                }
+            }
+
+            self.logical_definition_axioms.push(json!({
+                "definedClassId": defined.0.to_string(),
+                "genusIds": genus_ids,
+                "restrictions": restrictions,
+            }));
+        }
+    }
+}
+
+struct Reader<'a> {
+    b: &'a Build<ArcStr>,
+    ontology: ArcIRIMappedOntology,
+    /// The node type and property type of every node.
+    kinds: HashMap<String, (String, String)>,
+}
+
+impl<'a> Reader<'a> {
+    fn add(&mut self, axiom: Axiom<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        self.ontology.insert(AnnotatedAxiom { axiom, ann });
+    }
+
+    fn annotation(&self, ap: &str, av: AnnotationValue<ArcStr>) -> Annotation<ArcStr> {
+        Annotation {
+            ap: self.b.annotation_property(ap),
+            av,
+        }
+    }
+
+    fn assert(
+        &mut self,
+        subject: &IRI<ArcStr>,
+        ap: &str,
+        av: AnnotationValue<ArcStr>,
+        ann: BTreeSet<Annotation<ArcStr>>,
+    ) {
+        let axiom = Axiom::AnnotationAssertion(AnnotationAssertion {
+            subject: AnnotationSubject::IRI(subject.clone()),
+            ann: self.annotation(ap, av),
+        });
+        self.add(axiom, ann);
+    }
+
+    fn property_value(&self, pv: &Value) -> Result<Annotation<ArcStr>, String> {
+        Ok(self.annotation(string(pv, "pred")?, literal(string(pv, "val")?)))
+    }
+
+    /// The `xrefs` of a definition or synonym as `hasDbXref` axiom annotations.
+    fn xrefs(&self, value: &Value) -> BTreeSet<Annotation<ArcStr>> {
+        array(&value["xrefs"])
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|xref| self.annotation(HAS_DB_XREF, literal(xref)))
+            .collect()
+    }
+
+    fn graph(&mut self, graph: &Value, first: bool) -> Result<(), String> {
+        if first {
+            if let Some(id) = graph["id"].as_str() {
+                self.ontology.mut_id().iri = Some(self.b.iri(id));
+            }
+            if let Some(version) = graph["meta"]["version"].as_str() {
+                self.ontology.mut_id().viri = Some(self.b.iri(version));
+            }
+        }
+
+        for pv in array(&graph["meta"]["basicPropertyValues"]) {
+            let ann = self.property_value(pv)?;
+            self.add(Axiom::OntologyAnnotation(OntologyAnnotation(ann)), BTreeSet::new());
+        }
+
+        //Edges need the types of their nodes, so these are collected first
+        let nodes = array(&graph["nodes"]);
+        for node in nodes {
+            let kind = node["type"].as_str().unwrap_or_default();
+            let property_type = node["propertyType"].as_str().unwrap_or_default();
+            self.kinds.insert(
+                string(node, "id")?.to_string(),
+                (kind.to_string(), property_type.to_string()),
+            );
+        }
+        for node in nodes {
+            self.node(node)?;
+        }
+
+        for edge in array(&graph["edges"]) {
+            self.edge(string(edge, "sub")?, string(edge, "pred")?, string(edge, "obj")?);
+        }
+
+        for set in array(&graph["equivalentNodesSets"]) {
+            let ces = array(&set["nodeIds"])
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|id| ClassExpression::Class(self.b.class(id)))
+                .collect();
+            self.add(Axiom::EquivalentClasses(EquivalentClasses(ces)), BTreeSet::new());
+        }
+
+        for lda in array(&graph["logicalDefinitionAxioms"]) {
+            let defined = self.b.class(string(lda, "definedClassId")?);
+            let mut operands: Vec<ClassExpression<ArcStr>> = array(&lda["genusIds"])
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|id| ClassExpression::Class(self.b.class(id)))
+                .collect();
+            for r in array(&lda["restrictions"]) {
+                operands.push(ClassExpression::ObjectSomeValuesFrom {
+                    ope: ObjectPropertyExpression::ObjectProperty(
+                        self.b.object_property(string(r, "propertyId")?),
+                    ),
+                    bce: Box::new(ClassExpression::Class(self.b.class(string(r, "fillerId")?))),
+                });
+            }
+
+            let axiom = Axiom::EquivalentClasses(EquivalentClasses(vec![
+                ClassExpression::Class(defined),
+                ClassExpression::ObjectIntersectionOf(operands),
+            ]));
+            self.add(axiom, BTreeSet::new());
+        }
+
+        for dra in array(&graph["domainRangeAxioms"]) {
+            let p = self.b.object_property(string(dra, "predicateId")?);
+            for id in array(&dra["domainClassIds"]).iter().filter_map(Value::as_str) {
+                let axiom = Axiom::ObjectPropertyDomain(ObjectPropertyDomain {
+                    ope: ObjectPropertyExpression::ObjectProperty(p.clone()),
+                    ce: ClassExpression::Class(self.b.class(id)),
+                });
+                self.add(axiom, BTreeSet::new());
+            }
+            for id in array(&dra["rangeClassIds"]).iter().filter_map(Value::as_str) {
+                let axiom = Axiom::ObjectPropertyRange(ObjectPropertyRange {
+                    ope: ObjectPropertyExpression::ObjectProperty(p.clone()),
+                    ce: ClassExpression::Class(self.b.class(id)),
+                });
+                self.add(axiom, BTreeSet::new());
+            }
+        }
+
+        for pca in array(&graph["propertyChainAxioms"]) {
+            let chain = array(&pca["chainPredicateIds"])
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|id| ObjectPropertyExpression::ObjectProperty(self.b.object_property(id)))
+                .collect();
+            let axiom = Axiom::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyChain(chain),
+                sup: ObjectPropertyExpression::ObjectProperty(
+                    self.b.object_property(string(pca, "predicateId")?),
+                ),
+            });
+            self.add(axiom, BTreeSet::new());
+        }
+
+        Ok(())
+    }
+
+    fn node(&mut self, node: &Value) -> Result<(), String> {
+        let id = string(node, "id")?;
+        let iri = self.b.iri(id);
+
+        let declaration = match (node["type"].as_str(), node["propertyType"].as_str()) {
+            (Some("CLASS"), _) => Some(Axiom::DeclareClass(DeclareClass(self.b.class(id)))),
+            (Some("PROPERTY"), Some("ANNOTATION")) => Some(Axiom::DeclareAnnotationProperty(
+                DeclareAnnotationProperty(self.b.annotation_property(id)),
+            )),
+            (Some("PROPERTY"), Some("DATA")) => Some(Axiom::DeclareDataProperty(
+                DeclareDataProperty(self.b.data_property(id)),
+            )),
+            (Some("PROPERTY"), _) => Some(Axiom::DeclareObjectProperty(DeclareObjectProperty(
+                self.b.object_property(id),
+            ))),
+            (Some("INDIVIDUAL"), _) => Some(Axiom::DeclareNamedIndividual(
+                DeclareNamedIndividual(self.b.named_individual(id)),
+            )),
+            _ => None,
+        };
+        if let Some(axiom) = declaration {
+            self.add(axiom, BTreeSet::new());
+        }
+
+        if let Some(label) = node["lbl"].as_str() {
+            self.assert(&iri, RDFS_LABEL, literal(label), BTreeSet::new());
+        }
+
+        let meta = &node["meta"];
+        if let Some(definition) = meta["definition"]["val"].as_str() {
+            let xrefs = self.xrefs(&meta["definition"]);
+            self.assert(&iri, IAO_DEFINITION, literal(definition), xrefs);
+        }
+        for comment in array(&meta["comments"]).iter().filter_map(Value::as_str) {
+            self.assert(&iri, RDFS_COMMENT, literal(comment), BTreeSet::new());
+        }
+        for subset in array(&meta["subsets"]).iter().filter_map(Value::as_str) {
+            let av = AnnotationValue::IRI(self.b.iri(subset));
+            self.assert(&iri, IN_SUBSET, av, BTreeSet::new());
+        }
+        for xref in array(&meta["xrefs"]) {
+            self.assert(&iri, HAS_DB_XREF, literal(string(xref, "val")?), BTreeSet::new());
+        }
+        for synonym in array(&meta["synonyms"]) {
+            let pred = synonym["pred"].as_str().unwrap_or("hasRelatedSynonym");
+            let mut anns = self.xrefs(synonym);
+            if let Some(t) = synonym["synonymType"].as_str() {
+                anns.insert(self.annotation(HAS_SYNONYM_TYPE, AnnotationValue::IRI(self.b.iri(t))));
+            }
+            let ap = expand(pred, OBO_IN_OWL);
+            self.assert(&iri, &ap, literal(string(synonym, "val")?), anns);
+        }
+        if meta["deprecated"].as_bool() == Some(true) {
+            let av = AnnotationValue::Literal(Literal::Datatype {
+                literal: "true".to_string(),
+                datatype_iri: self.b.iri(XSD_BOOLEAN),
+            });
+            self.assert(&iri, OWL_DEPRECATED, av, BTreeSet::new());
+        }
+        for pv in array(&meta["basicPropertyValues"]) {
+            let ann = self.property_value(pv)?;
+            let axiom = Axiom::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(iri.clone()),
+                ann,
+            });
+            self.add(axiom, BTreeSet::new());
+        }
+
+        Ok(())
+    }
+
+    /// `is_a` edges between properties are sub property axioms, `type` edges class assertions
+    /// and other edges from individuals property assertions. All remaining edges are
+    /// existential restrictions on the subject class.
+    fn edge(&mut self, sub: &str, pred: &str, obj: &str) {
+        let (kind, property_type) = self.kinds.get(sub).cloned().unwrap_or_default();
+        let b = self.b;
+
+        let axiom = match pred {
+            "is_a" | "subPropertyOf" if kind == "PROPERTY" => match property_type.as_str() {
+                "ANNOTATION" => Axiom::SubAnnotationPropertyOf(SubAnnotationPropertyOf {
+                    sub: b.annotation_property(sub),
+                    sup: b.annotation_property(obj),
+                }),
+                "DATA" => Axiom::SubDataPropertyOf(SubDataPropertyOf {
+                    sub: b.data_property(sub),
+                    sup: b.data_property(obj),
+                }),
+                _ => Axiom::SubObjectPropertyOf(SubObjectPropertyOf {
+                    sub: SubObjectPropertyExpression::ObjectPropertyExpression(
+                        ObjectPropertyExpression::ObjectProperty(b.object_property(sub)),
+                    ),
+                    sup: ObjectPropertyExpression::ObjectProperty(b.object_property(obj)),
+                }),
+            },
+            "is_a" => Axiom::SubClassOf(SubClassOf {
+                sub: ClassExpression::Class(b.class(sub)),
+                sup: ClassExpression::Class(b.class(obj)),
+            }),
+            "type" => Axiom::ClassAssertion(ClassAssertion {
+                ce: ClassExpression::Class(b.class(obj)),
+                i: Individual::Named(b.named_individual(sub)),
+            }),
+            "inverseOf" => Axiom::InverseObjectProperties(InverseObjectProperties(
+                b.object_property(sub),
+                b.object_property(obj),
+            )),
+            _ if kind == "INDIVIDUAL" => Axiom::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                ope: ObjectPropertyExpression::ObjectProperty(b.object_property(pred)),
+                from: Individual::Named(b.named_individual(sub)),
+                to: Individual::Named(b.named_individual(obj)),
+            }),
+            _ => Axiom::SubClassOf(SubClassOf {
+                sub: ClassExpression::Class(b.class(sub)),
+                sup: ClassExpression::ObjectSomeValuesFrom {
+                    ope: ObjectPropertyExpression::ObjectProperty(b.object_property(pred)),
+                    bce: Box::new(ClassExpression::Class(b.class(obj))),
+                },
+            }),
+        };
+
+        self.add(axiom, BTreeSet::new());
+    }
+}
+
+fn value_string(av: &AnnotationValue<ArcStr>) -> String {
+    match av {
+        AnnotationValue::Literal(
+            Literal::Simple { literal }
+            | Literal::Language { literal, .. }
+            | Literal::Datatype { literal, .. },
+        ) => literal.clone(),
+        AnnotationValue::IRI(iri) => iri.to_string(),
+    }
+}
+
+fn property_value(ann: &Annotation<ArcStr>) -> Value {
+    json!({ "pred": ann.ap.0.to_string(), "val": value_string(&ann.av) })
+}
+
+fn literal(value: &str) -> AnnotationValue<ArcStr> {
+    AnnotationValue::Literal(Literal::Simple {
+        literal: value.to_string(),
+    })
+}
+
+/// Appends `item` to the list in `value`, which is created if missing.
+fn push(value: &mut Value, item: Value) {
+    match value {
+        Value::Array(items) => items.push(item),
+        _ => *value = Value::Array(vec![item]),
+    }
+}
+
+/// The items of a list, which may be missing.
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map_or(&[], |items| items.as_slice())
+}
+
+fn string<'v>(value: &'v Value, field: &str) -> Result<&'v str, String> {
+    value[field]
+        .as_str()
+        .ok_or_else(|| format!("Expected the field '{}' in {}", field, value))
+}
+
+/// Expands a local name like `hasExactSynonym` in the namespace `ns`, keeping full IRIs.
+fn expand(name: &str, ns: &str) -> String {
+    if name.contains(':') {
+        name.to_string()
+    } else {
+        format!("{}{}", ns, name)
+    }
+}
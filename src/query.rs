@@ -0,0 +1,308 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use crate::model::Term;
+
+/// Var(name: str)
+///
+/// A placeholder in a pattern which matches any value and binds it to `name`.
+///
+/// All occurrences of a variable in a pattern must match equal values. The variable `_`
+/// matches any value without binding it.
+#[pyclass(module = "pyhornedowl")]
+#[derive(Clone)]
+pub struct Var {
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl Var {
+    #[new]
+    fn new(name: String) -> Self {
+        Var { name }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Var({:?})", self.name)
+    }
+}
+
+/// Pattern(kind: Union[str, type], *args, **kwargs)
+///
+/// A partially specified model object used to find axioms by their structure.
+///
+/// `kind` is a model class or its name. Positional arguments match the fields in the order of
+/// the model class constructor, keyword arguments match fields by name. Each argument may be a
+/// model object, a string (matching strings and IRIs), an integer, a list, a `Var` or a nested
+/// `Pattern`. Fields without an argument match any value.
+///
+/// Calling a model class constructor with a `Var` or `Pattern` among its arguments builds a
+/// pattern as well, e.g. `SubClassOf(Var("x"), Class(IRI.parse(":A")))`.
+///
+/// The expressions of set-like axioms and class expressions such as `EquivalentClasses` or
+/// `ObjectIntersectionOf` are matched in any order, each element against a different expression.
+#[pyclass(module = "pyhornedowl")]
+pub struct Pattern {
+    kind: String,
+    args: Vec<PyObject>,
+    kwargs: Vec<(String, PyObject)>,
+}
+
+#[pymethods]
+impl Pattern {
+    #[new]
+    #[pyo3(signature = (kind, *args, **kwargs))]
+    fn new(kind: &PyAny, args: &PyTuple, kwargs: Option<&PyDict>) -> PyResult<Self> {
+        let kind = match kind.downcast::<PyType>() {
+            Ok(ty) => ty.name()?.to_string(),
+            Err(_) => kind.extract()?,
+        };
+
+        let args = args.iter().map(PyObject::from).collect();
+        let kwargs = match kwargs {
+            Some(kwargs) => kwargs
+                .iter()
+                .map(|(k, v)| Ok((k.extract()?, PyObject::from(v))))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Pattern { kind, args, kwargs })
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let mut args = vec![format!("{:?}", self.kind)];
+        for a in &self.args {
+            args.push(a.as_ref(py).repr()?.to_string());
+        }
+        for (k, v) in &self.kwargs {
+            args.push(format!("{}={}", k, v.as_ref(py).repr()?));
+        }
+
+        Ok(format!("Pattern({})", args.join(", ")))
+    }
+}
+
+/// A pattern converted from its Python representation.
+#[derive(Debug, Clone)]
+pub enum Pat {
+    Var(String),
+    Node(String, Vec<Pat>, Vec<(String, Pat)>),
+    List(Vec<Pat>),
+    Str(String),
+    Value(Term),
+}
+
+impl Pat {
+    pub fn from_py(ob: &PyAny) -> PyResult<Pat> {
+        if let Ok(var) = ob.extract::<PyRef<Var>>() {
+            return Ok(Pat::Var(var.name.clone()));
+        }
+
+        if let Ok(pattern) = ob.extract::<PyRef<Pattern>>() {
+            let py = ob.py();
+            let args = pattern
+                .args
+                .iter()
+                .map(|a| Pat::from_py(a.as_ref(py)))
+                .collect::<PyResult<_>>()?;
+            let kwargs = pattern
+                .kwargs
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), Pat::from_py(v.as_ref(py))?)))
+                .collect::<PyResult<_>>()?;
+
+            return Ok(Pat::Node(pattern.kind.clone(), args, kwargs));
+        }
+
+        if let Some(term) = Term::from_py(ob) {
+            return Ok(Pat::from_term(term));
+        }
+
+        if let Ok(s) = ob.extract::<String>() {
+            return Ok(Pat::Str(s));
+        }
+
+        if let Ok(n) = ob.extract::<u32>() {
+            return Ok(Pat::Value(Term::Int(n)));
+        }
+
+        if let Ok(items) = ob.extract::<Vec<&PyAny>>() {
+            return Ok(Pat::List(
+                items
+                    .into_iter()
+                    .map(Pat::from_py)
+                    .collect::<PyResult<_>>()?,
+            ));
+        }
+
+        Err(PyTypeError::new_err(format!(
+            "Cannot use {} in a pattern",
+            ob.repr()?
+        )))
+    }
+
+    /// A pattern matching `term` and the terms only differing from it in the order of unordered
+    /// lists, e.g. the class expressions of an `EquivalentClasses` axiom.
+    fn from_term(term: Term) -> Pat {
+        match term {
+            Term::Node(kind, fields) => Pat::Node(
+                kind.to_string(),
+                fields.into_iter().map(|(_, t)| Pat::from_term(t)).collect(),
+                Vec::new(),
+            ),
+            Term::List(terms) | Term::Set(terms) => {
+                Pat::List(terms.into_iter().map(Pat::from_term).collect())
+            }
+            term => Pat::Value(term),
+        }
+    }
+
+    /// The model class name matched by this pattern, if it is a `Pattern` or a model object.
+    pub fn kind(&self) -> Option<&str> {
+        match self {
+            Pat::Node(kind, _, _) => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// Matches `term` against this pattern and returns every distinct set of variable bindings,
+    /// each in the order the variables were first bound.
+    ///
+    /// Unordered lists can match in several ways, e.g. `DisjointClasses([Var("x"), Var("y")])`
+    /// matches a `DisjointClasses` axiom of two classes with both assignments of `x` and `y`.
+    pub fn matches(&self, term: &Term) -> Vec<Bindings> {
+        let mut matches: Vec<Bindings> = Vec::new();
+        for bindings in self.match_into(term, Vec::new()) {
+            let same = |m: &Bindings| m.len() == bindings.len() && m.iter().all(|b| bindings.contains(b));
+            if !matches.iter().any(same) {
+                matches.push(bindings);
+            }
+        }
+        matches
+    }
+
+    fn match_into(&self, term: &Term, bindings: Bindings) -> Vec<Bindings> {
+        match (self, term) {
+            (Pat::Var(name), _) if name == "_" => vec![bindings],
+            (Pat::Var(name), _) => match bindings.iter().find(|(n, _)| n == name) {
+                Some((_, bound)) if bound == term => vec![bindings],
+                Some(_) => Vec::new(),
+                None => {
+                    let mut bindings = bindings;
+                    bindings.push((name.clone(), term.clone()));
+                    vec![bindings]
+                }
+            },
+            (Pat::Value(value), _) if value == term => vec![bindings],
+            (Pat::Str(s), Term::String(t)) if s == t => vec![bindings],
+            (Pat::Str(s), Term::IRI(iri)) if s == &iri.to_string() => vec![bindings],
+            (Pat::List(pats), Term::List(terms)) if pats.len() == terms.len() => {
+                match_each(pats.iter().zip(terms), bindings, false)
+            }
+            (Pat::List(pats), Term::Set(terms)) => match_unordered(pats, terms, bindings),
+            (Pat::Node(kind, args, kwargs), Term::Node(name, fields))
+                if kind == name && args.len() <= fields.len() =>
+            {
+                let mut pairs: Vec<(&Pat, &Term)> =
+                    args.iter().zip(fields.iter().map(|(_, t)| t)).collect();
+                for (k, p) in kwargs {
+                    match fields.iter().find(|(f, _)| f == k) {
+                        Some((_, t)) => pairs.push((p, t)),
+                        None => return Vec::new(),
+                    }
+                }
+
+                match_each(pairs, bindings, UNORDERED.contains(name))
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Matches a field of a model object, whose lists are unordered for the classes in `UNORDERED`.
+    fn match_field(&self, term: &Term, bindings: Bindings, unordered: bool) -> Vec<Bindings> {
+        match (self, term) {
+            (Pat::List(pats), Term::List(terms)) if unordered => {
+                match_unordered(pats, terms, bindings)
+            }
+            _ => self.match_into(term, bindings),
+        }
+    }
+}
+
+/// Variable bindings in the order the variables were first bound.
+pub type Bindings = Vec<(String, Term)>;
+
+/// Matches each pattern against its term and returns the bindings of every way they all match.
+fn match_each<'a>(
+    pairs: impl IntoIterator<Item = (&'a Pat, &'a Term)>,
+    bindings: Bindings,
+    unordered: bool,
+) -> Vec<Bindings> {
+    pairs.into_iter().fold(vec![bindings], |alternatives, (p, t)| {
+        alternatives
+            .into_iter()
+            .flat_map(|bindings| p.match_field(t, bindings, unordered))
+            .collect()
+    })
+}
+
+/// Model classes whose lists hold sets of expressions, which patterns match in any order.
+const UNORDERED: [&str; 15] = [
+    "EquivalentClasses",
+    "DisjointClasses",
+    "DisjointUnion",
+    "ObjectIntersectionOf",
+    "ObjectUnionOf",
+    "ObjectOneOf",
+    "DataIntersectionOf",
+    "DataUnionOf",
+    "DataOneOf",
+    "EquivalentObjectProperties",
+    "DisjointObjectProperties",
+    "EquivalentDataProperties",
+    "DisjointDataProperties",
+    "SameIndividual",
+    "DifferentIndividuals",
+];
+
+/// Matches the patterns against the terms in any order, each pattern against a different term.
+///
+/// Returns the bindings of every assignment of patterns to terms that matches.
+fn match_unordered(pats: &[Pat], terms: &[Term], bindings: Bindings) -> Vec<Bindings> {
+    fn assign(
+        pats: &[Pat],
+        terms: &[Term],
+        used: &mut [bool],
+        bindings: Bindings,
+        matches: &mut Vec<Bindings>,
+    ) {
+        let (p, rest) = match pats.split_first() {
+            Some(first) => first,
+            None => {
+                matches.push(bindings);
+                return;
+            }
+        };
+
+        for (i, t) in terms.iter().enumerate() {
+            if used[i] {
+                continue;
+            }
+
+            for attempt in p.match_into(t, bindings.clone()) {
+                used[i] = true;
+                assign(rest, terms, used, attempt, matches);
+                used[i] = false;
+            }
+        }
+    }
+
+    let mut matches = Vec::new();
+    if pats.len() == terms.len() {
+        assign(pats, terms, &mut vec![false; terms.len()], bindings, &mut matches);
+    }
+    matches
+}
@@ -0,0 +1,723 @@
+use horned_owl::model::{
+    AnnotatedAxiom, Annotation, AnnotationSubject, AnnotationValue, ArcStr, Axiom,
+    ClassExpression, DataRange, Facet, Individual, Literal, ObjectPropertyExpression,
+    Ontology, PropertyExpression, SubObjectPropertyExpression, IRI,
+};
+use horned_owl::ontology::iri_mapped::ArcIRIMappedOntology;
+
+use pyo3::prelude::*;
+
+use std::collections::HashMap;
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// A node of the RDF graph.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Node {
+    IRI(String),
+    Blank(String),
+    Literal {
+        value: String,
+        datatype: String,
+        lang: Option<String>,
+    },
+}
+
+impl Node {
+    fn iri(iri: &IRI<ArcStr>) -> Node {
+        Node::IRI(iri.to_string())
+    }
+
+    fn vocab(ns: &str, name: &str) -> Node {
+        Node::IRI(format!("{}{}", ns, name))
+    }
+
+    fn typed(value: String, datatype: &str) -> Node {
+        Node::Literal {
+            value,
+            datatype: format!("{}{}", XSD, datatype),
+            lang: None,
+        }
+    }
+}
+
+impl IntoPy<PyObject> for Node {
+    /// IRIs become strings, blank nodes `_:id` strings and literals the matching Python value.
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Node::IRI(iri) => iri.into_py(py),
+            Node::Blank(id) => format!("_:{}", id).into_py(py),
+            Node::Literal {
+                value, datatype, ..
+            } => {
+                let local = datatype.strip_prefix(XSD).unwrap_or("");
+                match local {
+                    "boolean" => (value == "true" || value == "1").into_py(py),
+                    "integer" | "int" | "long" | "short" | "byte" | "nonNegativeInteger"
+                    | "positiveInteger" | "nonPositiveInteger" | "negativeInteger" => value
+                        .parse::<i64>()
+                        .map(|n| n.into_py(py))
+                        .unwrap_or_else(|_| value.into_py(py)),
+                    "decimal" | "double" | "float" => value
+                        .parse::<f64>()
+                        .map(|n| n.into_py(py))
+                        .unwrap_or_else(|_| value.into_py(py)),
+                    _ => value.into_py(py),
+                }
+            }
+        }
+    }
+}
+
+pub type Triple = (Node, Node, Node);
+
+/// The RDF view of an ontology as given by the OWL 2 mapping to RDF graphs.
+#[derive(Default)]
+pub struct Graph {
+    pub triples: Vec<Triple>,
+    blanks: usize,
+    by_subject: HashMap<Node, Vec<usize>>,
+    by_predicate: HashMap<Node, Vec<usize>>,
+    by_object: HashMap<Node, Vec<usize>>,
+}
+
+/// The part of an axiom that its annotations are attached to.
+enum Main {
+    /// The axiom maps to this triple, annotations are attached to its reification.
+    Triple(Triple),
+    /// The axiom maps to this blank node, annotations are attached to it directly.
+    Node(Node),
+}
+
+impl Graph {
+    /// Maps all axioms of `ontology` to triples.
+    pub fn from_ontology(ontology: &ArcIRIMappedOntology) -> Graph {
+        let mut graph = Graph::default();
+
+        let id = ontology.id();
+        let onto = match &id.iri {
+            Some(iri) => Node::iri(iri),
+            None => graph.blank(),
+        };
+        graph.add(onto.clone(), Node::vocab(RDF, "type"), Node::vocab(OWL, "Ontology"));
+        if let Some(viri) = &id.viri {
+            graph.add(onto.clone(), Node::vocab(OWL, "versionIRI"), Node::iri(viri));
+        }
+
+        for aax in ontology.iter() {
+            graph.add_axiom(&onto, aax);
+        }
+
+        graph
+    }
+
+    /// Returns the triples matching the given nodes, where `None` matches any node.
+    pub fn matching<'a>(
+        &'a self,
+        s: Option<&Node>,
+        p: Option<&Node>,
+        o: Option<&Node>,
+    ) -> Box<dyn Iterator<Item = &'a Triple> + 'a> {
+        //Start from the smallest candidate set
+        let candidates = [
+            lookup(&self.by_subject, s),
+            lookup(&self.by_predicate, p),
+            lookup(&self.by_object, o),
+        ]
+        .iter()
+        .flatten()
+        .min_by_key(|c| c.len())
+        .copied();
+
+        let (s, p, o) = (s.cloned(), p.cloned(), o.cloned());
+        let filter = move |t: &&Triple| {
+            s.as_ref().map_or(true, |s| &t.0 == s)
+                && p.as_ref().map_or(true, |p| &t.1 == p)
+                && o.as_ref().map_or(true, |o| &t.2 == o)
+        };
+
+        match candidates {
+            Some(indices) => Box::new(indices.iter().map(move |i| &self.triples[*i]).filter(filter)),
+            None => Box::new(self.triples.iter().filter(filter)),
+        }
+    }
+
+    fn blank(&mut self) -> Node {
+        self.blanks += 1;
+        Node::Blank(format!("genid{}", self.blanks))
+    }
+
+    fn add(&mut self, s: Node, p: Node, o: Node) {
+        let i = self.triples.len();
+        self.by_subject.entry(s.clone()).or_default().push(i);
+        self.by_predicate.entry(p.clone()).or_default().push(i);
+        self.by_object.entry(o.clone()).or_default().push(i);
+        self.triples.push((s, p, o));
+    }
+
+    fn add_typed(&mut self, s: Node, ns: &str, name: &str) {
+        self.add(s, Node::vocab(RDF, "type"), Node::vocab(ns, name));
+    }
+
+    fn list(&mut self, items: Vec<Node>) -> Node {
+        let mut rest = Node::vocab(RDF, "nil");
+        for item in items.into_iter().rev() {
+            let cell = self.blank();
+            self.add(cell.clone(), Node::vocab(RDF, "first"), item);
+            self.add(cell.clone(), Node::vocab(RDF, "rest"), rest);
+            rest = cell;
+        }
+        rest
+    }
+
+    /// Adds a triple for each consecutive pair of `items` and returns the first one.
+    fn pairwise(&mut self, items: Vec<Node>, p: Node) -> Option<Main> {
+        let mut main = None;
+        for pair in items.windows(2) {
+            self.add(pair[0].clone(), p.clone(), pair[1].clone());
+            main.get_or_insert(Main::Triple((pair[0].clone(), p.clone(), pair[1].clone())));
+        }
+        main
+    }
+
+    /// Adds `n = 2` items as a single triple and more as a typed node with a list of members.
+    fn disjoint(
+        &mut self,
+        items: Vec<Node>,
+        pair: &str,
+        all: &str,
+        members: &str,
+    ) -> Option<Main> {
+        if items.len() == 2 {
+            return self.triple(items[0].clone(), Node::vocab(OWL, pair), items[1].clone());
+        }
+
+        let node = self.blank();
+        self.add_typed(node.clone(), OWL, all);
+        let list = self.list(items);
+        self.add(node.clone(), Node::vocab(OWL, members), list);
+        Some(Main::Node(node))
+    }
+
+    fn triple(&mut self, s: Node, p: Node, o: Node) -> Option<Main> {
+        self.add(s.clone(), p.clone(), o.clone());
+        Some(Main::Triple((s, p, o)))
+    }
+
+    fn add_axiom(&mut self, onto: &Node, aax: &AnnotatedAxiom<ArcStr>) {
+        let main = self.axiom(onto, &aax.axiom);
+
+        if aax.ann.is_empty() {
+            return;
+        }
+
+        let target = match main {
+            Some(Main::Node(node)) => node,
+            Some(Main::Triple((s, p, o))) => {
+                let node = self.blank();
+                self.add_typed(node.clone(), OWL, "Axiom");
+                self.add(node.clone(), Node::vocab(OWL, "annotatedSource"), s);
+                self.add(node.clone(), Node::vocab(OWL, "annotatedProperty"), p);
+                self.add(node.clone(), Node::vocab(OWL, "annotatedTarget"), o);
+                node
+            }
+            None => return,
+        };
+
+        for ann in &aax.ann {
+            self.annotation(target.clone(), ann);
+        }
+    }
+
+    fn annotation(&mut self, subject: Node, ann: &Annotation<ArcStr>) -> Option<Main> {
+        let value = match &ann.av {
+            AnnotationValue::Literal(l) => self.literal(l),
+            AnnotationValue::IRI(iri) => Node::iri(iri),
+        };
+        self.triple(subject, Node::iri(&ann.ap.0), value)
+    }
+
+    fn axiom(&mut self, onto: &Node, axiom: &Axiom<ArcStr>) -> Option<Main> {
+        let rdf_type = Node::vocab(RDF, "type");
+
+        match axiom {
+            Axiom::OntologyAnnotation(oa) => self.annotation(onto.clone(), &oa.0),
+            Axiom::Import(imp) => {
+                self.triple(onto.clone(), Node::vocab(OWL, "imports"), Node::iri(&imp.0))
+            }
+            Axiom::DeclareClass(d) => {
+                self.triple(Node::iri(&(d.0).0), rdf_type, Node::vocab(OWL, "Class"))
+            }
+            Axiom::DeclareObjectProperty(d) => {
+                self.triple(Node::iri(&(d.0).0), rdf_type, Node::vocab(OWL, "ObjectProperty"))
+            }
+            Axiom::DeclareAnnotationProperty(d) => self.triple(
+                Node::iri(&(d.0).0),
+                rdf_type,
+                Node::vocab(OWL, "AnnotationProperty"),
+            ),
+            Axiom::DeclareDataProperty(d) => self.triple(
+                Node::iri(&(d.0).0),
+                rdf_type,
+                Node::vocab(OWL, "DatatypeProperty"),
+            ),
+            Axiom::DeclareNamedIndividual(d) => self.triple(
+                Node::iri(&(d.0).0),
+                rdf_type,
+                Node::vocab(OWL, "NamedIndividual"),
+            ),
+            Axiom::DeclareDatatype(d) => {
+                self.triple(Node::iri(&(d.0).0), rdf_type, Node::vocab(RDFS, "Datatype"))
+            }
+            Axiom::SubClassOf(sc) => {
+                let sub = self.class_expression(&sc.sub);
+                let sup = self.class_expression(&sc.sup);
+                self.triple(sub, Node::vocab(RDFS, "subClassOf"), sup)
+            }
+            Axiom::EquivalentClasses(ec) => {
+                let ces = ec.0.iter().map(|ce| self.class_expression(ce)).collect();
+                self.pairwise(ces, Node::vocab(OWL, "equivalentClass"))
+            }
+            Axiom::DisjointClasses(dc) => {
+                let ces = dc.0.iter().map(|ce| self.class_expression(ce)).collect();
+                self.disjoint(ces, "disjointWith", "AllDisjointClasses", "members")
+            }
+            Axiom::DisjointUnion(du) => {
+                let ces = du.1.iter().map(|ce| self.class_expression(ce)).collect();
+                let list = self.list(ces);
+                self.triple(Node::iri(&(du.0).0), Node::vocab(OWL, "disjointUnionOf"), list)
+            }
+            Axiom::SubObjectPropertyOf(sop) => {
+                let sup = self.object_property(&sop.sup);
+                match &sop.sub {
+                    SubObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                        let opes = chain.iter().map(|ope| self.object_property(ope)).collect();
+                        let list = self.list(opes);
+                        self.triple(sup, Node::vocab(OWL, "propertyChainAxiom"), list)
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyExpression(ope) => {
+                        let sub = self.object_property(ope);
+                        self.triple(sub, Node::vocab(RDFS, "subPropertyOf"), sup)
+                    }
+                }
+            }
+            Axiom::EquivalentObjectProperties(ep) => {
+                let opes = ep.0.iter().map(|ope| self.object_property(ope)).collect();
+                self.pairwise(opes, Node::vocab(OWL, "equivalentProperty"))
+            }
+            Axiom::DisjointObjectProperties(dp) => {
+                let opes = dp.0.iter().map(|ope| self.object_property(ope)).collect();
+                self.disjoint(opes, "propertyDisjointWith", "AllDisjointProperties", "members")
+            }
+            Axiom::InverseObjectProperties(ip) => self.triple(
+                Node::iri(&(ip.0).0),
+                Node::vocab(OWL, "inverseOf"),
+                Node::iri(&(ip.1).0),
+            ),
+            Axiom::ObjectPropertyDomain(d) => {
+                let ope = self.object_property(&d.ope);
+                let ce = self.class_expression(&d.ce);
+                self.triple(ope, Node::vocab(RDFS, "domain"), ce)
+            }
+            Axiom::ObjectPropertyRange(r) => {
+                let ope = self.object_property(&r.ope);
+                let ce = self.class_expression(&r.ce);
+                self.triple(ope, Node::vocab(RDFS, "range"), ce)
+            }
+            Axiom::FunctionalObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "FunctionalProperty"))
+            }
+            Axiom::InverseFunctionalObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "InverseFunctionalProperty"))
+            }
+            Axiom::ReflexiveObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "ReflexiveProperty"))
+            }
+            Axiom::IrreflexiveObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "IrreflexiveProperty"))
+            }
+            Axiom::SymmetricObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "SymmetricProperty"))
+            }
+            Axiom::AsymmetricObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "AsymmetricProperty"))
+            }
+            Axiom::TransitiveObjectProperty(p) => {
+                let ope = self.object_property(&p.0);
+                self.triple(ope, rdf_type, Node::vocab(OWL, "TransitiveProperty"))
+            }
+            Axiom::SubDataPropertyOf(sdp) => self.triple(
+                Node::iri(&sdp.sub.0),
+                Node::vocab(RDFS, "subPropertyOf"),
+                Node::iri(&sdp.sup.0),
+            ),
+            Axiom::EquivalentDataProperties(ep) => {
+                let dps = ep.0.iter().map(|dp| Node::iri(&dp.0)).collect();
+                self.pairwise(dps, Node::vocab(OWL, "equivalentProperty"))
+            }
+            Axiom::DisjointDataProperties(dp) => {
+                let dps = dp.0.iter().map(|dp| Node::iri(&dp.0)).collect();
+                self.disjoint(dps, "propertyDisjointWith", "AllDisjointProperties", "members")
+            }
+            Axiom::DataPropertyDomain(d) => {
+                let ce = self.class_expression(&d.ce);
+                self.triple(Node::iri(&d.dp.0), Node::vocab(RDFS, "domain"), ce)
+            }
+            Axiom::DataPropertyRange(r) => {
+                let dr = self.data_range(&r.dr);
+                self.triple(Node::iri(&r.dp.0), Node::vocab(RDFS, "range"), dr)
+            }
+            Axiom::FunctionalDataProperty(p) => {
+                self.triple(Node::iri(&(p.0).0), rdf_type, Node::vocab(OWL, "FunctionalProperty"))
+            }
+            Axiom::DatatypeDefinition(dd) => {
+                let dr = self.data_range(&dd.range);
+                self.triple(Node::iri(&dd.kind.0), Node::vocab(OWL, "equivalentClass"), dr)
+            }
+            Axiom::HasKey(hk) => {
+                let ce = self.class_expression(&hk.ce);
+                let pes = hk
+                    .vpe
+                    .iter()
+                    .map(|pe| match pe {
+                        PropertyExpression::ObjectPropertyExpression(ope) => {
+                            self.object_property(ope)
+                        }
+                        PropertyExpression::DataProperty(dp) => Node::iri(&dp.0),
+                        PropertyExpression::AnnotationProperty(ap) => Node::iri(&ap.0),
+                    })
+                    .collect();
+                let list = self.list(pes);
+                self.triple(ce, Node::vocab(OWL, "hasKey"), list)
+            }
+            Axiom::SameIndividual(si) => {
+                let is = si.0.iter().map(|i| self.individual(i)).collect();
+                self.pairwise(is, Node::vocab(OWL, "sameAs"))
+            }
+            Axiom::DifferentIndividuals(di) => {
+                let is = di.0.iter().map(|i| self.individual(i)).collect();
+                self.disjoint(is, "differentFrom", "AllDifferent", "distinctMembers")
+            }
+            Axiom::ClassAssertion(ca) => {
+                let i = self.individual(&ca.i);
+                let ce = self.class_expression(&ca.ce);
+                self.triple(i, rdf_type, ce)
+            }
+            Axiom::ObjectPropertyAssertion(opa) => {
+                let from = self.individual(&opa.from);
+                let to = self.individual(&opa.to);
+                match &opa.ope {
+                    ObjectPropertyExpression::ObjectProperty(op) => {
+                        self.triple(from, Node::iri(&op.0), to)
+                    }
+                    ObjectPropertyExpression::InverseObjectProperty(op) => {
+                        self.triple(to, Node::iri(&op.0), from)
+                    }
+                }
+            }
+            Axiom::NegativeObjectPropertyAssertion(nopa) => {
+                let ope = self.object_property(&nopa.ope);
+                let from = self.individual(&nopa.from);
+                let to = self.individual(&nopa.to);
+                self.negative_assertion(ope, from, "targetIndividual", to)
+            }
+            Axiom::DataPropertyAssertion(dpa) => {
+                let from = self.individual(&dpa.from);
+                let to = self.literal(&dpa.to);
+                self.triple(from, Node::iri(&dpa.dp.0), to)
+            }
+            Axiom::NegativeDataPropertyAssertion(ndpa) => {
+                let from = self.individual(&ndpa.from);
+                let to = self.literal(&ndpa.to);
+                self.negative_assertion(Node::iri(&ndpa.dp.0), from, "targetValue", to)
+            }
+            Axiom::AnnotationAssertion(aa) => {
+                let subject = match &aa.subject {
+                    AnnotationSubject::IRI(iri) => Node::iri(iri),
+                    AnnotationSubject::AnonymousIndividual(anon) => {
+                        Node::Blank(anon.0.to_string())
+                    }
+                };
+                self.annotation(subject, &aa.ann)
+            }
+            Axiom::SubAnnotationPropertyOf(sap) => self.triple(
+                Node::iri(&sap.sub.0),
+                Node::vocab(RDFS, "subPropertyOf"),
+                Node::iri(&sap.sup.0),
+            ),
+            Axiom::AnnotationPropertyDomain(d) => {
+                self.triple(Node::iri(&d.ap.0), Node::vocab(RDFS, "domain"), Node::iri(&d.iri))
+            }
+            Axiom::AnnotationPropertyRange(r) => {
+                self.triple(Node::iri(&r.ap.0), Node::vocab(RDFS, "range"), Node::iri(&r.iri))
+            }
+        }
+    }
+
+    fn negative_assertion(&mut self, p: Node, from: Node, target: &str, to: Node) -> Option<Main> {
+        let node = self.blank();
+        self.add_typed(node.clone(), OWL, "NegativePropertyAssertion");
+        self.add(node.clone(), Node::vocab(OWL, "sourceIndividual"), from);
+        self.add(node.clone(), Node::vocab(OWL, "assertionProperty"), p);
+        self.add(node.clone(), Node::vocab(OWL, target), to);
+        Some(Main::Node(node))
+    }
+
+    fn individual(&mut self, i: &Individual<ArcStr>) -> Node {
+        match i {
+            Individual::Named(ni) => Node::iri(&ni.0),
+            Individual::Anonymous(anon) => Node::Blank(anon.0.to_string()),
+        }
+    }
+
+    fn literal(&mut self, l: &Literal<ArcStr>) -> Node {
+        match l {
+            Literal::Simple { literal } => Node::typed(literal.to_string(), "string"),
+            Literal::Language { literal, lang } => Node::Literal {
+                value: literal.to_string(),
+                datatype: format!("{}langString", RDF),
+                lang: Some(lang.to_string()),
+            },
+            Literal::Datatype {
+                literal,
+                datatype_iri,
+            } => Node::Literal {
+                value: literal.to_string(),
+                datatype: datatype_iri.to_string(),
+                lang: None,
+            },
+        }
+    }
+
+    fn object_property(&mut self, ope: &ObjectPropertyExpression<ArcStr>) -> Node {
+        match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => Node::iri(&op.0),
+            ObjectPropertyExpression::InverseObjectProperty(op) => {
+                let node = self.blank();
+                self.add(node.clone(), Node::vocab(OWL, "inverseOf"), Node::iri(&op.0));
+                node
+            }
+        }
+    }
+
+    fn restriction(&mut self, property: Node) -> Node {
+        let node = self.blank();
+        self.add_typed(node.clone(), OWL, "Restriction");
+        self.add(node.clone(), Node::vocab(OWL, "onProperty"), property);
+        node
+    }
+
+    /// Adds a cardinality restriction, which is unqualified if `filler` is `unqualified`.
+    ///
+    /// `name` is `min`, `max` or empty for exact cardinalities.
+    fn cardinality(
+        &mut self,
+        property: Node,
+        n: u32,
+        name: &str,
+        on: &str,
+        filler: Node,
+        unqualified: &Node,
+    ) -> Node {
+        let node = self.restriction(property);
+        let n = Node::typed(n.to_string(), "nonNegativeInteger");
+
+        let (unqualified_p, qualified_p) = if name.is_empty() {
+            ("cardinality".to_string(), "qualifiedCardinality".to_string())
+        } else {
+            (format!("{}Cardinality", name), format!("{}QualifiedCardinality", name))
+        };
+
+        if &filler == unqualified {
+            self.add(node.clone(), Node::vocab(OWL, &unqualified_p), n);
+        } else {
+            self.add(node.clone(), Node::vocab(OWL, &qualified_p), n);
+            self.add(node.clone(), Node::vocab(OWL, on), filler);
+        }
+        node
+    }
+
+    fn class_expression(&mut self, ce: &ClassExpression<ArcStr>) -> Node {
+        let thing = Node::vocab(OWL, "Thing");
+
+        match ce {
+            ClassExpression::Class(c) => Node::iri(&c.0),
+            ClassExpression::ObjectIntersectionOf(ces) => {
+                self.class_list(ces, "intersectionOf")
+            }
+            ClassExpression::ObjectUnionOf(ces) => self.class_list(ces, "unionOf"),
+            ClassExpression::ObjectComplementOf(bce) => {
+                let ce = self.class_expression(bce);
+                let node = self.blank();
+                self.add_typed(node.clone(), OWL, "Class");
+                self.add(node.clone(), Node::vocab(OWL, "complementOf"), ce);
+                node
+            }
+            ClassExpression::ObjectOneOf(is) => {
+                let is = is.iter().map(|i| self.individual(i)).collect();
+                let list = self.list(is);
+                let node = self.blank();
+                self.add_typed(node.clone(), OWL, "Class");
+                self.add(node.clone(), Node::vocab(OWL, "oneOf"), list);
+                node
+            }
+            ClassExpression::ObjectSomeValuesFrom { ope, bce } => {
+                let ope = self.object_property(ope);
+                let ce = self.class_expression(bce);
+                let node = self.restriction(ope);
+                self.add(node.clone(), Node::vocab(OWL, "someValuesFrom"), ce);
+                node
+            }
+            ClassExpression::ObjectAllValuesFrom { ope, bce } => {
+                let ope = self.object_property(ope);
+                let ce = self.class_expression(bce);
+                let node = self.restriction(ope);
+                self.add(node.clone(), Node::vocab(OWL, "allValuesFrom"), ce);
+                node
+            }
+            ClassExpression::ObjectHasValue { ope, i } => {
+                let ope = self.object_property(ope);
+                let i = self.individual(i);
+                let node = self.restriction(ope);
+                self.add(node.clone(), Node::vocab(OWL, "hasValue"), i);
+                node
+            }
+            ClassExpression::ObjectHasSelf(ope) => {
+                let ope = self.object_property(ope);
+                let node = self.restriction(ope);
+                let t = Node::typed("true".to_string(), "boolean");
+                self.add(node.clone(), Node::vocab(OWL, "hasSelf"), t);
+                node
+            }
+            ClassExpression::ObjectMinCardinality { n, ope, bce } => {
+                let ope = self.object_property(ope);
+                let ce = self.class_expression(bce);
+                self.cardinality(ope, *n, "min", "onClass", ce, &thing)
+            }
+            ClassExpression::ObjectMaxCardinality { n, ope, bce } => {
+                let ope = self.object_property(ope);
+                let ce = self.class_expression(bce);
+                self.cardinality(ope, *n, "max", "onClass", ce, &thing)
+            }
+            ClassExpression::ObjectExactCardinality { n, ope, bce } => {
+                let ope = self.object_property(ope);
+                let ce = self.class_expression(bce);
+                self.cardinality(ope, *n, "", "onClass", ce, &thing)
+            }
+            ClassExpression::DataSomeValuesFrom { dp, dr } => {
+                let dr = self.data_range(dr);
+                let node = self.restriction(Node::iri(&dp.0));
+                self.add(node.clone(), Node::vocab(OWL, "someValuesFrom"), dr);
+                node
+            }
+            ClassExpression::DataAllValuesFrom { dp, dr } => {
+                let dr = self.data_range(dr);
+                let node = self.restriction(Node::iri(&dp.0));
+                self.add(node.clone(), Node::vocab(OWL, "allValuesFrom"), dr);
+                node
+            }
+            ClassExpression::DataHasValue { dp, l } => {
+                let l = self.literal(l);
+                let node = self.restriction(Node::iri(&dp.0));
+                self.add(node.clone(), Node::vocab(OWL, "hasValue"), l);
+                node
+            }
+            ClassExpression::DataMinCardinality { n, dp, dr } => {
+                let dr = self.data_range(dr);
+                let literal = Node::vocab(RDFS, "Literal");
+                self.cardinality(Node::iri(&dp.0), *n, "min", "onDataRange", dr, &literal)
+            }
+            ClassExpression::DataMaxCardinality { n, dp, dr } => {
+                let dr = self.data_range(dr);
+                let literal = Node::vocab(RDFS, "Literal");
+                self.cardinality(Node::iri(&dp.0), *n, "max", "onDataRange", dr, &literal)
+            }
+            ClassExpression::DataExactCardinality { n, dp, dr } => {
+                let dr = self.data_range(dr);
+                let literal = Node::vocab(RDFS, "Literal");
+                self.cardinality(Node::iri(&dp.0), *n, "", "onDataRange", dr, &literal)
+            }
+        }
+    }
+
+    fn class_list(&mut self, ces: &[ClassExpression<ArcStr>], p: &str) -> Node {
+        let ces = ces.iter().map(|ce| self.class_expression(ce)).collect();
+        let list = self.list(ces);
+        let node = self.blank();
+        self.add_typed(node.clone(), OWL, "Class");
+        self.add(node.clone(), Node::vocab(OWL, p), list);
+        node
+    }
+
+    fn data_range(&mut self, dr: &DataRange<ArcStr>) -> Node {
+        let (p, o) = match dr {
+            DataRange::Datatype(dt) => return Node::iri(&dt.0),
+            DataRange::DataIntersectionOf(drs) => {
+                let drs = drs.iter().map(|dr| self.data_range(dr)).collect();
+                ("intersectionOf", self.list(drs))
+            }
+            DataRange::DataUnionOf(drs) => {
+                let drs = drs.iter().map(|dr| self.data_range(dr)).collect();
+                ("unionOf", self.list(drs))
+            }
+            DataRange::DataComplementOf(dr) => ("datatypeComplementOf", self.data_range(dr)),
+            DataRange::DataOneOf(ls) => {
+                let ls = ls.iter().map(|l| self.literal(l)).collect();
+                ("oneOf", self.list(ls))
+            }
+            DataRange::DatatypeRestriction(dt, frs) => {
+                let restrictions = frs
+                    .iter()
+                    .map(|fr| {
+                        let node = self.blank();
+                        let l = self.literal(&fr.l);
+                        self.add(node.clone(), facet(&fr.f), l);
+                        node
+                    })
+                    .collect();
+                let list = self.list(restrictions);
+
+                let node = self.blank();
+                self.add_typed(node.clone(), RDFS, "Datatype");
+                self.add(node.clone(), Node::vocab(OWL, "onDatatype"), Node::iri(&dt.0));
+                self.add(node.clone(), Node::vocab(OWL, "withRestrictions"), list);
+                return node;
+            }
+        };
+
+        let node = self.blank();
+        self.add_typed(node.clone(), RDFS, "Datatype");
+        self.add(node.clone(), Node::vocab(OWL, p), o);
+        node
+    }
+}
+
+fn lookup<'a>(index: &'a HashMap<Node, Vec<usize>>, node: Option<&Node>) -> Option<&'a [usize]> {
+    node.map(|n| index.get(n).map(|v| v.as_slice()).unwrap_or(&[]))
+}
+
+fn facet(f: &Facet) -> Node {
+    let name = match f {
+        Facet::Length => "length",
+        Facet::MinLength => "minLength",
+        Facet::MaxLength => "maxLength",
+        Facet::Pattern => "pattern",
+        Facet::MinInclusive => "minInclusive",
+        Facet::MinExclusive => "minExclusive",
+        Facet::MaxInclusive => "maxInclusive",
+        Facet::MaxExclusive => "maxExclusive",
+        Facet::TotalDigits => "totalDigits",
+        Facet::FractionDigits => "fractionDigits",
+        Facet::LangRange => return Node::vocab(RDF, "langRange"),
+    };
+    Node::vocab(XSD, name)
+}
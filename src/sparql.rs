@@ -0,0 +1,874 @@
+use curie::{Curie, PrefixMapping};
+use regex::RegexBuilder;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::rdf::{Graph, Node};
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+const BUILTIN_PREFIXES: [(&str, &str); 4] = [
+    ("rdf", RDF),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("xsd", XSD),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Var(String),
+    IRI(String),
+    PName(String, String),
+    Blank(String),
+    String(String),
+    LangTag(String),
+    Number(String),
+    Word(String),
+    Punct(&'static str),
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    let is_name = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+
+    //Reads a name starting at `start`. Dots are allowed inside but not at the end.
+    let read_name = |start: usize| -> usize {
+        let mut end = start;
+        while end < chars.len()
+            && (is_name(chars[end])
+                || (chars[end] == '.' && end + 1 < chars.len() && is_name(chars[end + 1])))
+        {
+            end += 1;
+        }
+        end
+    };
+
+    //An IRI is enclosed in angle brackets without whitespace, otherwise '<' is an operator
+    let iri_end = |start: usize| -> Option<usize> {
+        if chars[start] != '<' {
+            return None;
+        }
+        chars[start + 1..]
+            .iter()
+            .position(|c| *c == '>' || c.is_whitespace())
+            .map(|p| start + 1 + p)
+            .filter(|end| chars[*end] == '>')
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '?' || c == '$' {
+            let end = read_name(i + 1);
+            if end == i + 1 {
+                return Err(format!("Expected a variable name at position {}", i));
+            }
+            tokens.push(Token::Var(chars[i + 1..end].iter().collect()));
+            i = end;
+        } else if let Some(end) = iri_end(i) {
+            tokens.push(Token::IRI(chars[i + 1..end].iter().collect()));
+            i = end + 1;
+        } else if c == '"' || c == '\'' {
+            let mut value = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err("Unterminated string literal".to_string()),
+                    Some('\\') => {
+                        let escaped = match chars.get(i + 1) {
+                            Some('n') => '\n',
+                            Some('t') => '\t',
+                            Some('r') => '\r',
+                            Some(e) => *e,
+                            None => return Err("Unterminated string literal".to_string()),
+                        };
+                        value.push(escaped);
+                        i += 2;
+                    }
+                    Some(q) if *q == c => {
+                        i += 1;
+                        break;
+                    }
+                    Some(other) => {
+                        value.push(*other);
+                        i += 1;
+                    }
+                }
+            }
+            tokens.push(Token::String(value));
+        } else if c == '@' {
+            let end = read_name(i + 1);
+            tokens.push(Token::LangTag(chars[i + 1..end].iter().collect()));
+            i = end;
+        } else if c == '_' && chars.get(i + 1) == Some(&':') {
+            let end = read_name(i + 2);
+            tokens.push(Token::Blank(chars[i + 2..end].iter().collect()));
+            i = end;
+        } else if c.is_ascii_digit()
+            || ((c == '-' || c == '+') && chars.get(i + 1).map_or(false, |d| d.is_ascii_digit()))
+        {
+            //A sign directly before a digit belongs to the number
+            let mut end = i + 1;
+            while end < chars.len()
+                && (chars[end].is_ascii_digit()
+                    || (chars[end] == '.'
+                        && chars.get(end + 1).map_or(false, |c| c.is_ascii_digit())))
+            {
+                end += 1;
+            }
+            tokens.push(Token::Number(chars[i..end].iter().collect()));
+            i = end;
+        } else if is_name(c) || c == ':' {
+            let end = read_name(i);
+            let word: String = chars[i..end].iter().collect();
+            if chars.get(end) == Some(&':') {
+                let local_end = read_name(end + 1);
+                tokens.push(Token::PName(word, chars[end + 1..local_end].iter().collect()));
+                i = local_end;
+            } else {
+                tokens.push(Token::Word(word));
+                i = end;
+            }
+        } else {
+            let two: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            let punct = match two.as_str() {
+                "^^" => Some("^^"),
+                "!=" => Some("!="),
+                "<=" => Some("<="),
+                ">=" => Some(">="),
+                "&&" => Some("&&"),
+                "||" => Some("||"),
+                _ => None,
+            };
+
+            if let Some(p) = punct {
+                tokens.push(Token::Punct(p));
+                i += 2;
+            } else {
+                let p = match c {
+                    '{' => "{",
+                    '}' => "}",
+                    '(' => "(",
+                    ')' => ")",
+                    '.' => ".",
+                    ';' => ";",
+                    ',' => ",",
+                    '*' => "*",
+                    '=' => "=",
+                    '<' => "<",
+                    '>' => ">",
+                    '!' => "!",
+                    _ => return Err(format!("Unexpected character '{}' at position {}", c, i)),
+                };
+                tokens.push(Token::Punct(p));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone)]
+enum TermPattern {
+    Var(String),
+    Node(Node),
+}
+
+#[derive(Debug, Clone)]
+enum Element {
+    Triple(TermPattern, TermPattern, TermPattern),
+    Optional(Group),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Group {
+    elements: Vec<Element>,
+    filters: Vec<Expr>,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Var(String),
+    Node(Node),
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(&'static str, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+/// A parsed SPARQL SELECT query.
+///
+/// Supports basic graph patterns (including the `;` and `,` abbreviations), FILTER,
+/// OPTIONAL, DISTINCT, LIMIT and OFFSET.
+pub struct Query {
+    /// The projected variables, `None` for `SELECT *`.
+    vars: Option<Vec<String>>,
+    distinct: bool,
+    pattern: Group,
+    limit: Option<usize>,
+    offset: usize,
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    prefixes: HashMap<String, String>,
+    mapping: &'a PrefixMapping,
+    blanks: HashSet<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| "Unexpected end of query".to_string())?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_punct(&self, p: &str) -> bool {
+        matches!(self.peek(), Some(Token::Punct(q)) if *q == p)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_punct(&mut self, p: &str) -> Result<(), String> {
+        match self.next()? {
+            Token::Punct(q) if q == p => Ok(()),
+            t => Err(format!("Expected '{}' but found {:?}", p, t)),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), String> {
+        match self.next()? {
+            Token::Word(w) if w.eq_ignore_ascii_case(keyword) => Ok(()),
+            t => Err(format!("Expected {} but found {:?}", keyword, t)),
+        }
+    }
+
+    fn expand(&self, prefix: &str, local: &str) -> Result<String, String> {
+        if let Some(ns) = self.prefixes.get(prefix) {
+            return Ok(format!("{}{}", ns, local));
+        }
+
+        let curie = Curie::new(Some(prefix), local);
+        if let Ok(iri) = self.mapping.expand_curie(&curie) {
+            return Ok(iri);
+        }
+
+        BUILTIN_PREFIXES
+            .iter()
+            .find(|(p, _)| *p == prefix)
+            .map(|(_, ns)| format!("{}{}", ns, local))
+            .ok_or_else(|| format!("Unknown prefix '{}'", prefix))
+    }
+
+    fn query(&mut self) -> Result<Query, String> {
+        while self.is_keyword("PREFIX") {
+            self.next()?;
+            let prefix = match self.next()? {
+                Token::PName(prefix, local) if local.is_empty() => prefix,
+                t => return Err(format!("Expected a prefix name but found {:?}", t)),
+            };
+            let ns = match self.next()? {
+                Token::IRI(ns) => ns,
+                t => return Err(format!("Expected an IRI but found {:?}", t)),
+            };
+            self.prefixes.insert(prefix, ns);
+        }
+
+        self.expect_keyword("SELECT")?;
+
+        let distinct = self.is_keyword("DISTINCT");
+        if distinct {
+            self.next()?;
+        }
+
+        let vars = if self.is_punct("*") {
+            self.next()?;
+            None
+        } else {
+            let mut vars = Vec::new();
+            while let Some(Token::Var(v)) = self.peek() {
+                vars.push(v.clone());
+                self.next()?;
+            }
+            if vars.is_empty() {
+                return Err("Expected '*' or variables after SELECT".to_string());
+            }
+            Some(vars)
+        };
+
+        if self.is_keyword("WHERE") {
+            self.next()?;
+        }
+        let pattern = self.group()?;
+
+        let mut limit = None;
+        let mut offset = 0;
+        while self.is_keyword("LIMIT") || self.is_keyword("OFFSET") {
+            let is_limit = self.is_keyword("LIMIT");
+            self.next()?;
+            let n = match self.next()? {
+                Token::Number(n) => n.parse::<usize>().map_err(|e| e.to_string())?,
+                t => return Err(format!("Expected a number but found {:?}", t)),
+            };
+            if is_limit {
+                limit = Some(n);
+            } else {
+                offset = n;
+            }
+        }
+
+        if let Some(t) = self.peek() {
+            return Err(format!("Unexpected {:?} after the query", t));
+        }
+
+        Ok(Query {
+            vars,
+            distinct,
+            pattern,
+            limit,
+            offset,
+        })
+    }
+
+    fn group(&mut self) -> Result<Group, String> {
+        self.expect_punct("{")?;
+        let mut group = Group::default();
+
+        loop {
+            if self.is_punct("}") {
+                self.next()?;
+                return Ok(group);
+            } else if self.is_punct(".") {
+                self.next()?;
+            } else if self.is_keyword("FILTER") {
+                self.next()?;
+                let expr = if self.is_punct("(") {
+                    self.primary()?
+                } else {
+                    self.call()?
+                };
+                group.filters.push(expr);
+            } else if self.is_keyword("OPTIONAL") {
+                self.next()?;
+                group.elements.push(Element::Optional(self.group()?));
+            } else {
+                self.triples(&mut group.elements)?;
+            }
+        }
+    }
+
+    fn triples(&mut self, elements: &mut Vec<Element>) -> Result<(), String> {
+        let subject = self.term()?;
+
+        loop {
+            let predicate = if self.is_keyword("a") {
+                self.next()?;
+                TermPattern::Node(Node::IRI(format!("{}type", RDF)))
+            } else {
+                self.term()?
+            };
+
+            loop {
+                let object = self.term()?;
+                elements.push(Element::Triple(subject.clone(), predicate.clone(), object));
+                if !self.is_punct(",") {
+                    break;
+                }
+                self.next()?;
+            }
+
+            if !self.is_punct(";") {
+                return Ok(());
+            }
+            self.next()?;
+
+            //A trailing ';' may end the triples
+            if self.is_punct(".") || self.is_punct("}") {
+                return Ok(());
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<TermPattern, String> {
+        Ok(match self.next()? {
+            Token::Var(v) => TermPattern::Var(v),
+            Token::Blank(b) => {
+                //Blank nodes in patterns act as variables which are never projected
+                let name = format!("_:{}", b);
+                self.blanks.insert(name.clone());
+                TermPattern::Var(name)
+            }
+            Token::IRI(iri) => TermPattern::Node(Node::IRI(iri)),
+            Token::PName(prefix, local) => TermPattern::Node(Node::IRI(self.expand(&prefix, &local)?)),
+            t => {
+                self.pos -= 1;
+                TermPattern::Node(self.literal().map_err(|_| format!("Unexpected {:?}", t))?)
+            }
+        })
+    }
+
+    fn literal(&mut self) -> Result<Node, String> {
+        match self.next()? {
+            Token::String(value) => {
+                if let Some(Token::LangTag(lang)) = self.peek().cloned() {
+                    self.next()?;
+                    return Ok(Node::Literal {
+                        value,
+                        datatype: format!("{}langString", RDF),
+                        lang: Some(lang),
+                    });
+                }
+
+                let datatype = if self.is_punct("^^") {
+                    self.next()?;
+                    match self.next()? {
+                        Token::IRI(iri) => iri,
+                        Token::PName(prefix, local) => self.expand(&prefix, &local)?,
+                        t => return Err(format!("Expected a datatype but found {:?}", t)),
+                    }
+                } else {
+                    format!("{}string", XSD)
+                };
+
+                Ok(Node::Literal {
+                    value,
+                    datatype,
+                    lang: None,
+                })
+            }
+            Token::Number(n) => {
+                let datatype = if n.contains('.') { "decimal" } else { "integer" };
+                Ok(Node::Literal {
+                    value: n,
+                    datatype: format!("{}{}", XSD, datatype),
+                    lang: None,
+                })
+            }
+            Token::Word(w) if w == "true" || w == "false" => Ok(Node::Literal {
+                value: w,
+                datatype: format!("{}boolean", XSD),
+                lang: None,
+            }),
+            t => Err(format!("Expected a term but found {:?}", t)),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.and()?;
+        while self.is_punct("||") {
+            self.next()?;
+            left = Expr::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut left = self.comparison()?;
+        while self.is_punct("&&") {
+            self.next()?;
+            left = Expr::And(Box::new(left), Box::new(self.comparison()?));
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.unary()?;
+        for op in ["=", "!=", "<", ">", "<=", ">="].iter() {
+            if self.is_punct(op) {
+                self.next()?;
+                let right = self.unary()?;
+                return Ok(Expr::Compare(*op, Box::new(left), Box::new(right)));
+            }
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.is_punct("!") {
+            self.next()?;
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.peek().cloned() {
+            Some(Token::Punct("(")) => {
+                self.next()?;
+                let expr = self.expr()?;
+                self.expect_punct(")")?;
+                Ok(expr)
+            }
+            Some(Token::Var(v)) => {
+                self.next()?;
+                Ok(Expr::Var(v))
+            }
+            Some(Token::IRI(iri)) => {
+                self.next()?;
+                Ok(Expr::Node(Node::IRI(iri)))
+            }
+            Some(Token::PName(prefix, local)) => {
+                self.next()?;
+                Ok(Expr::Node(Node::IRI(self.expand(&prefix, &local)?)))
+            }
+            Some(Token::Word(w)) if w != "true" && w != "false" => self.call(),
+            _ => Ok(Expr::Node(self.literal()?)),
+        }
+    }
+
+    fn call(&mut self) -> Result<Expr, String> {
+        let name = match self.next()? {
+            Token::Word(w) => w.to_lowercase(),
+            t => return Err(format!("Expected a function but found {:?}", t)),
+        };
+
+        self.expect_punct("(")?;
+        let mut args = Vec::new();
+        while !self.is_punct(")") {
+            args.push(self.expr()?);
+            if self.is_punct(",") {
+                self.next()?;
+            }
+        }
+        self.next()?;
+
+        Ok(Expr::Call(name, args))
+    }
+}
+
+type Solution = HashMap<String, Node>;
+
+impl Query {
+    /// Parses a query. Prefixes not declared in the query are expanded with `mapping`.
+    pub fn parse(query: &str, mapping: &PrefixMapping) -> Result<Query, String> {
+        let mut parser = Parser {
+            tokens: tokenize(query)?,
+            pos: 0,
+            prefixes: HashMap::new(),
+            mapping,
+            blanks: HashSet::new(),
+        };
+
+        let mut query = parser.query()?;
+        if query.vars.is_none() {
+            let mut vars = Vec::new();
+            collect_vars(&query.pattern, &mut vars);
+            vars.retain(|v| !parser.blanks.contains(v));
+            query.vars = Some(vars);
+        }
+
+        Ok(query)
+    }
+
+    /// The names of the projected variables.
+    pub fn vars(&self) -> &[String] {
+        self.vars.as_deref().unwrap_or(&[])
+    }
+
+    /// Evaluates the query and returns a row of values for each solution.
+    pub fn evaluate(&self, graph: &Graph) -> Vec<Vec<Option<Node>>> {
+        let solutions = eval_group(graph, &self.pattern, vec![Solution::new()]);
+
+        let mut seen = HashSet::new();
+        solutions
+            .into_iter()
+            .map(|s| self.vars().iter().map(|v| s.get(v).cloned()).collect::<Vec<_>>())
+            .filter(|row| !self.distinct || seen.insert(row.clone()))
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+fn collect_vars(group: &Group, vars: &mut Vec<String>) {
+    for element in &group.elements {
+        match element {
+            Element::Triple(s, p, o) => {
+                for t in [s, p, o].iter() {
+                    if let TermPattern::Var(v) = t {
+                        if !vars.contains(v) {
+                            vars.push(v.clone());
+                        }
+                    }
+                }
+            }
+            Element::Optional(g) => collect_vars(g, vars),
+        }
+    }
+}
+
+fn eval_group(graph: &Graph, group: &Group, mut solutions: Vec<Solution>) -> Vec<Solution> {
+    for element in &group.elements {
+        solutions = match element {
+            Element::Triple(s, p, o) => solutions
+                .into_iter()
+                .flat_map(|solution| match_triple(graph, s, p, o, solution))
+                .collect(),
+            Element::Optional(optional) => solutions
+                .into_iter()
+                .flat_map(|solution| {
+                    let extended = eval_group(graph, optional, vec![solution.clone()]);
+                    if extended.is_empty() {
+                        vec![solution]
+                    } else {
+                        extended
+                    }
+                })
+                .collect(),
+        };
+    }
+
+    solutions
+        .into_iter()
+        .filter(|solution| {
+            group
+                .filters
+                .iter()
+                .all(|f| eval(f, solution).and_then(|v| truth(&v)).unwrap_or(false))
+        })
+        .collect()
+}
+
+fn match_triple(
+    graph: &Graph,
+    s: &TermPattern,
+    p: &TermPattern,
+    o: &TermPattern,
+    solution: Solution,
+) -> Vec<Solution> {
+    let resolve = |t: &TermPattern| match t {
+        TermPattern::Var(v) => solution.get(v).cloned(),
+        TermPattern::Node(n) => Some(n.clone()),
+    };
+    let (sn, pn, on) = (resolve(s), resolve(p), resolve(o));
+
+    graph
+        .matching(sn.as_ref(), pn.as_ref(), on.as_ref())
+        .filter_map(|(ts, tp, to)| {
+            let mut extended = solution.clone();
+            for (pattern, node) in [(s, ts), (p, tp), (o, to)].iter() {
+                if let TermPattern::Var(v) = pattern {
+                    match extended.get(v) {
+                        //The same variable may occur twice in one triple
+                        Some(bound) if bound != *node => return None,
+                        Some(_) => (),
+                        None => {
+                            extended.insert(v.clone(), (*node).clone());
+                        }
+                    }
+                }
+            }
+            Some(extended)
+        })
+        .collect()
+}
+
+fn boolean(b: bool) -> Node {
+    Node::Literal {
+        value: b.to_string(),
+        datatype: format!("{}boolean", XSD),
+        lang: None,
+    }
+}
+
+fn string(s: String) -> Node {
+    Node::Literal {
+        value: s,
+        datatype: format!("{}string", XSD),
+        lang: None,
+    }
+}
+
+/// Returns the numeric value of a literal with a numeric datatype.
+fn number(node: &Node) -> Option<f64> {
+    match node {
+        Node::Literal {
+            value, datatype, ..
+        } if is_numeric(datatype) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+fn is_numeric(datatype: &str) -> bool {
+    datatype.strip_prefix(XSD).map_or(false, |local| {
+        matches!(
+            local,
+            "integer"
+                | "decimal"
+                | "double"
+                | "float"
+                | "int"
+                | "long"
+                | "short"
+                | "byte"
+                | "nonNegativeInteger"
+                | "positiveInteger"
+                | "nonPositiveInteger"
+                | "negativeInteger"
+                | "unsignedInt"
+                | "unsignedLong"
+                | "unsignedShort"
+                | "unsignedByte"
+        )
+    })
+}
+
+/// The effective boolean value of a node.
+fn truth(node: &Node) -> Option<bool> {
+    match node {
+        Node::Literal {
+            value, datatype, ..
+        } => {
+            if datatype == &format!("{}boolean", XSD) {
+                Some(value == "true" || value == "1")
+            } else if let Some(n) = number(node) {
+                Some(n != 0.0 && !n.is_nan())
+            } else {
+                Some(!value.is_empty())
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The string value of a node, as given by the SPARQL `str` function.
+fn lexical(node: &Node) -> String {
+    match node {
+        Node::IRI(iri) => iri.clone(),
+        Node::Blank(b) => b.clone(),
+        Node::Literal { value, .. } => value.clone(),
+    }
+}
+
+/// Evaluates an expression. Returns `None` if evaluation fails, e.g. for unbound variables.
+fn eval(expr: &Expr, solution: &Solution) -> Option<Node> {
+    match expr {
+        Expr::Var(v) => solution.get(v).cloned(),
+        Expr::Node(n) => Some(n.clone()),
+        Expr::Or(l, r) => {
+            let l = eval(l, solution).and_then(|v| truth(&v));
+            let r = eval(r, solution).and_then(|v| truth(&v));
+            match (l, r) {
+                (Some(true), _) | (_, Some(true)) => Some(boolean(true)),
+                (Some(false), Some(false)) => Some(boolean(false)),
+                _ => None,
+            }
+        }
+        Expr::And(l, r) => {
+            let l = eval(l, solution).and_then(|v| truth(&v));
+            let r = eval(r, solution).and_then(|v| truth(&v));
+            match (l, r) {
+                (Some(false), _) | (_, Some(false)) => Some(boolean(false)),
+                (Some(true), Some(true)) => Some(boolean(true)),
+                _ => None,
+            }
+        }
+        Expr::Not(e) => eval(e, solution)
+            .and_then(|v| truth(&v))
+            .map(|b| boolean(!b)),
+        Expr::Compare(op, l, r) => {
+            let l = eval(l, solution)?;
+            let r = eval(r, solution)?;
+            compare(op, &l, &r).map(boolean)
+        }
+        Expr::Call(name, args) => call(name, args, solution),
+    }
+}
+
+fn compare(op: &str, l: &Node, r: &Node) -> Option<bool> {
+    let ordering = match (number(l), number(r)) {
+        (Some(a), Some(b)) => a.partial_cmp(&b)?,
+        _ => match (l, r) {
+            (Node::Literal { .. }, Node::Literal { .. }) => {
+                if op == "=" || op == "!=" {
+                    return Some((l == r) == (op == "="));
+                }
+                lexical(l).cmp(&lexical(r))
+            }
+            _ if op == "=" => return Some(l == r),
+            _ if op == "!=" => return Some(l != r),
+            _ => return None,
+        },
+    };
+
+    Some(match op {
+        "=" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        "<" => ordering.is_lt(),
+        ">" => ordering.is_gt(),
+        "<=" => ordering.is_le(),
+        ">=" => ordering.is_ge(),
+        _ => return None,
+    })
+}
+
+fn call(name: &str, args: &[Expr], solution: &Solution) -> Option<Node> {
+    if name == "bound" {
+        return match args {
+            [Expr::Var(v)] => Some(boolean(solution.contains_key(v))),
+            _ => None,
+        };
+    }
+
+    let values = args
+        .iter()
+        .map(|a| eval(a, solution))
+        .collect::<Option<Vec<Node>>>()?;
+
+    match (name, values.as_slice()) {
+        ("str", [v]) => Some(string(lexical(v))),
+        ("lang", [Node::Literal { lang, .. }]) => Some(string(lang.clone().unwrap_or_default())),
+        ("datatype", [Node::Literal { datatype, .. }]) => Some(Node::IRI(datatype.clone())),
+        ("isiri", [v]) | ("isuri", [v]) => Some(boolean(matches!(v, Node::IRI(_)))),
+        ("isblank", [v]) => Some(boolean(matches!(v, Node::Blank(_)))),
+        ("isliteral", [v]) => Some(boolean(matches!(v, Node::Literal { .. }))),
+        ("strlen", [v]) => Some(Node::Literal {
+            value: lexical(v).chars().count().to_string(),
+            datatype: format!("{}integer", XSD),
+            lang: None,
+        }),
+        ("lcase", [v]) => Some(string(lexical(v).to_lowercase())),
+        ("ucase", [v]) => Some(string(lexical(v).to_uppercase())),
+        ("contains", [a, b]) => Some(boolean(lexical(a).contains(&lexical(b)))),
+        ("strstarts", [a, b]) => Some(boolean(lexical(a).starts_with(&lexical(b)))),
+        ("strends", [a, b]) => Some(boolean(lexical(a).ends_with(&lexical(b)))),
+        ("sameterm", [a, b]) => Some(boolean(a == b)),
+        ("regex", [text, pattern]) => regex(text, pattern, None),
+        ("regex", [text, pattern, flags]) => regex(text, pattern, Some(flags)),
+        _ => None,
+    }
+}
+
+fn regex(text: &Node, pattern: &Node, flags: Option<&Node>) -> Option<Node> {
+    let flags = flags.map(lexical).unwrap_or_default();
+    let re = RegexBuilder::new(&lexical(pattern))
+        .case_insensitive(flags.contains('i'))
+        .multi_line(flags.contains('m'))
+        .dot_matches_new_line(flags.contains('s'))
+        .build()
+        .ok()?;
+    Some(boolean(re.is_match(&lexical(text))))
+}
@@ -0,0 +1,56 @@
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+PREFIX = "https://example.com/"
+
+
+@pytest.fixture
+def iri():
+    """Returns the IRI of a test entity by its local name."""
+    def iri(name):
+        return PREFIX + name
+    return iri
+
+
+@pytest.fixture
+def cls(iri):
+    """Returns the class of a test entity by its local name."""
+    def cls(name):
+        return Class(IRI.parse(iri(name)))
+    return cls
+
+
+@pytest.fixture
+def annotation():
+    """Returns an annotation with a value for an annotation property by its IRI."""
+    def annotation(property, value):
+        return Annotation(AnnotationProperty(IRI.parse(property)), value)
+    return annotation
+
+
+@pytest.fixture
+def onto():
+    return pyhornedowl.PyIndexedOntology()
+
+
+@pytest.fixture
+def freeze():
+    """Turns model objects into hashable tuples of their fields, so that they can be compared by value."""
+    def freeze(value):
+        if isinstance(value, (list, tuple)):
+            return tuple(freeze(v) for v in value)
+        if isinstance(value, (set, frozenset)):
+            return frozenset(freeze(v) for v in value)
+        if isinstance(value, dict):
+            return frozenset((k, freeze(v)) for k, v in value.items())
+        if isinstance(value, IRI):
+            return ("IRI", str(value))
+        if isinstance(value, Facet):
+            return repr(value)
+        if type(value).__module__ != "pyhornedowl.model":
+            return value
+        fields = [name for name in dir(value) if not name.startswith("_") and not callable(getattr(value, name))]
+        return (type(value).__name__,) + tuple((name, freeze(getattr(value, name))) for name in fields)
+    return freeze
@@ -0,0 +1,97 @@
+import pytest
+
+from pyhornedowl.model import *
+
+LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+DEFINITION = "http://purl.obolibrary.org/obo/IAO_0000115"
+SYNONYM = "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym"
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+XSD_STRING = "http://www.w3.org/2001/XMLSchema#string"
+
+
+@pytest.fixture
+def values(onto, freeze):
+    """Returns the annotation values of a term for a property as frozen model objects."""
+    def values(iri, property):
+        return {
+            freeze(aax.axiom.ann.av)
+            for aax in onto.get_axioms_for_iri(iri)
+            if isinstance(aax.axiom, AnnotationAssertion) and str(aax.axiom.ann.ap.first) == property
+        }
+    return values
+
+
+def test_set_annotation_replaces_values(onto, iri):
+    onto.set_annotation(iri("A"), DEFINITION, "first definition")
+    onto.set_annotation(iri("A"), DEFINITION, "second definition")
+
+    assert onto.get_annotations(iri("A"), DEFINITION) == ["second definition"]
+
+
+def test_add_annotation_keeps_values(onto, iri):
+    onto.add_annotation(iri("A"), SYNONYM, "one")
+    onto.add_annotation(iri("A"), SYNONYM, "two")
+
+    assert sorted(onto.get_annotations(iri("A"), SYNONYM)) == ["one", "two"]
+
+
+def test_set_annotation_without_replace_adds(onto, iri):
+    onto.set_annotation(iri("A"), SYNONYM, "one")
+    onto.set_annotation(iri("A"), SYNONYM, "two", replace=False)
+
+    assert sorted(onto.get_annotations(iri("A"), SYNONYM)) == ["one", "two"]
+
+
+def test_value_types(onto, iri, freeze, values):
+    onto.add_annotation(iri("A"), SYNONYM, "Farbe", lang="de")
+    onto.add_annotation(iri("A"), SYNONYM, "colour", datatype=XSD_STRING)
+    onto.add_annotation(iri("A"), SEE_ALSO, IRI.parse(iri("B")))
+    onto.add_annotation(iri("A"), SEE_ALSO, SimpleLiteral("plain"))
+
+    assert values(iri("A"), SYNONYM) == {
+        freeze(LanguageLiteral("Farbe", "de")),
+        freeze(DatatypeLiteral("colour", IRI.parse(XSD_STRING))),
+    }
+    assert values(iri("A"), SEE_ALSO) == {freeze(IRI.parse(iri("B"))), freeze(SimpleLiteral("plain"))}
+
+
+def test_remove_annotations_only_removes_property(onto, iri):
+    onto.add_annotation(iri("A"), SYNONYM, "one")
+    onto.add_annotation(iri("A"), SYNONYM, "two")
+    onto.add_annotation(iri("A"), DEFINITION, "definition")
+    onto.add_annotation(iri("B"), SYNONYM, "other")
+
+    assert onto.remove_annotations(iri("A"), SYNONYM) == 2
+    assert onto.get_annotations(iri("A"), SYNONYM) == []
+    assert onto.get_annotations(iri("A"), DEFINITION) == ["definition"]
+    assert onto.get_annotations(iri("B"), SYNONYM) == ["other"]
+    assert onto.remove_annotations(iri("A"), SYNONYM) == 0
+
+
+def test_set_label_updates_label_index(onto, iri):
+    onto.set_label(iri("A"), "old")
+    onto.set_label(iri("A"), "new")
+
+    assert onto.get_iri_for_label("new") == iri("A")
+    assert onto.get_iri_for_label("old") is None
+
+
+def test_set_label_keeps_other_languages(onto, iri):
+    onto.add_annotation(iri("A"), LABEL, "x", lang="de")
+    onto.set_label(iri("A"), "old")
+    onto.set_label(iri("A"), "new")
+
+    assert sorted(onto.get_annotations(iri("A"), LABEL)) == ["new", "x"]
+
+
+def test_set_annotation_replaces_same_language(onto, iri, freeze, values):
+    onto.add_annotation(iri("A"), SYNONYM, "Farbe", lang="de")
+    onto.add_annotation(iri("A"), SYNONYM, "colour", lang="en")
+    onto.add_annotation(iri("A"), SYNONYM, "plain")
+    onto.set_annotation(iri("A"), SYNONYM, "Tönung", lang="DE")
+
+    assert values(iri("A"), SYNONYM) == {
+        freeze(LanguageLiteral("Tönung", "DE")),
+        freeze(LanguageLiteral("colour", "en")),
+        freeze(SimpleLiteral("plain")),
+    }
@@ -0,0 +1,34 @@
+import pytest
+
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def built(onto, cls):
+    onto.add_axiom(DeclareClass(cls("A")))
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")))
+    onto.add_axiom(SubClassOf(sub=cls("B"), sup=cls("C")))
+    onto.add_axiom(DisjointClasses([cls("A"), cls("C")]))
+    return onto
+
+
+def test_get_axioms_of_kind_by_name_and_type(built, cls, freeze):
+    by_name = built.get_axioms_of_kind("SubClassOf")
+    by_type = built.get_axioms_of_kind(SubClassOf)
+
+    expected = freeze({SubClassOf(sub=cls("A"), sup=cls("B")), SubClassOf(sub=cls("B"), sup=cls("C"))})
+    assert freeze({aax.axiom for aax in by_name}) == expected
+    assert freeze({aax.axiom for aax in by_type}) == expected
+
+
+def test_get_axioms_of_absent_kind(built):
+    assert built.get_axioms_of_kind("EquivalentClasses") == []
+
+
+def test_unknown_kind(built):
+    with pytest.raises(ValueError):
+        built.get_axioms_of_kind("SubClass")
+
+
+def test_count_axioms_by_kind(built):
+    assert built.count_axioms_by_kind() == {"DeclareClass": 1, "SubClassOf": 2, "DisjointClasses": 1}
@@ -0,0 +1,50 @@
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+
+
+@pytest.fixture
+def chain(cls):
+    """Returns a generator of the axioms of a subclass chain C0 <- C1 <- ... <- Cn."""
+    def chain(n):
+        return (SubClassOf(sub=cls(f"C{i + 1}"), sup=cls(f"C{i}")) for i in range(n))
+    return chain
+
+
+def test_add_axioms_from_generator(onto, iri, chain):
+    assert onto.add_axioms(chain(100)) == 100
+    assert len(onto.get_axioms()) == 100
+    assert onto.get_superclasses(iri("C100")) == {iri("C99")}
+    assert iri("C100") in pyhornedowl.get_descendants(onto, iri("C0"))
+
+
+def test_add_axioms_counts_only_new_axioms(onto, chain):
+    onto.add_axioms(chain(10))
+
+    assert onto.add_axioms(chain(20)) == 10
+    assert len(onto.get_axioms()) == 20
+
+
+def test_add_axioms_accepts_annotated_axioms(onto, cls, annotation, freeze):
+    annotated = AnnotatedAxiom(SubClassOf(sub=cls("C1"), sup=cls("C0")), {annotation(COMMENT, SimpleLiteral("note"))})
+
+    assert onto.add_axioms([annotated, SubClassOf(sub=cls("C2"), sup=cls("C1"))]) == 2
+    assert freeze(annotated) in freeze(onto.get_axioms())
+
+
+def test_add_axioms_rejects_non_axioms(onto, cls):
+    with pytest.raises(TypeError):
+        onto.add_axioms([SubClassOf(sub=cls("C1"), sup=cls("C0")), "not an axiom"])
+    assert onto.get_axioms() == []
+
+
+def test_remove_axioms_from_generator(onto, iri, chain):
+    onto.add_axioms(chain(100))
+
+    assert onto.remove_axioms(ax for ax in chain(100) if str(ax.sup.first) != iri("C0")) == 99
+    assert len(onto.get_axioms()) == 1
+    assert pyhornedowl.get_descendants(onto, iri("C0")) == {iri("C1")}
+    assert onto.get_superclasses(iri("C2")) == set()
@@ -0,0 +1,55 @@
+import warnings
+
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def subclass(cls):
+    def subclass(sub, sup):
+        return SubClassOf(sub=cls(sub), sup=cls(sup))
+    return subclass
+
+
+@pytest.fixture
+def chain(onto, subclass):
+    onto.add_axiom(subclass("A", "B"))
+    onto.add_axiom(subclass("B", "C"))
+    return onto
+
+
+def test_ancestors_and_descendants(chain, iri):
+    assert pyhornedowl.get_ancestors(chain, iri("A")) == {iri("B"), iri("C")}
+    assert pyhornedowl.get_descendants(chain, iri("C")) == {iri("A"), iri("B")}
+
+
+def test_include_self_and_max_depth(chain, iri):
+    assert pyhornedowl.get_ancestors(chain, iri("A"), include_self=True) == {iri("A"), iri("B"), iri("C")}
+    assert pyhornedowl.get_ancestors(chain, iri("A"), max_depth=1) == {iri("B")}
+    assert pyhornedowl.get_descendants(chain, iri("C"), include_self=True, max_depth=0) == {iri("C")}
+
+
+def test_cycle_is_reported(chain, iri, subclass):
+    chain.add_axiom(subclass("C", "A"))
+
+    with pytest.warns(RuntimeWarning, match="Cycle detected"):
+        ancestors = pyhornedowl.get_ancestors(chain, iri("A"))
+
+    assert ancestors == {iri("B"), iri("C")}
+
+
+def test_acyclic_hierarchy_does_not_warn(chain, iri):
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        pyhornedowl.get_descendants(chain, iri("C"))
+
+
+def test_deep_hierarchy(onto, iri, subclass):
+    depth = 50000
+    for i in range(depth):
+        onto.add_axiom(subclass(str(i), str(i + 1)))
+
+    assert len(pyhornedowl.get_ancestors(onto, iri("0"))) == depth
+    assert len(pyhornedowl.get_descendants(onto, iri(str(depth)))) == depth
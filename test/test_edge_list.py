@@ -0,0 +1,56 @@
+import pytest
+
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def part_of(iri, cls):
+    def part_of(name):
+        return ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls(name))
+    return part_of
+
+
+@pytest.fixture
+def built(onto, iri, cls, part_of):
+    onto.add_axiom(SubClassOf(sub=cls("Finger"), sup=cls("BodyPart")))
+    onto.add_axiom(SubClassOf(sub=cls("Finger"), sup=part_of("Hand")))
+    onto.set_label(iri("Finger"), "finger")
+    onto.set_label(iri("part_of"), "part of")
+    return onto
+
+
+def test_edges_from_hierarchy_and_restrictions(built, iri):
+    assert set(built.to_edge_list()) == {
+        (iri("Finger"), "subClassOf", iri("BodyPart")),
+        (iri("Finger"), iri("part_of"), iri("Hand")),
+    }
+
+
+def test_relations_select_predicates(built, iri):
+    assert built.to_edge_list(relations=[iri("part_of")]) == [(iri("Finger"), iri("part_of"), iri("Hand"))]
+    assert built.to_edge_list(relations=["subClassOf"]) == [(iri("Finger"), "subClassOf", iri("BodyPart"))]
+
+
+def test_use_labels_falls_back_to_iris(built, iri):
+    assert set(built.to_edge_list(use_labels=True)) == {
+        ("finger", "subClassOf", iri("BodyPart")),
+        ("finger", "part of", iri("Hand")),
+    }
+
+
+def test_removed_restriction_is_no_edge(built, iri, cls, part_of):
+    built.remove_axiom(SubClassOf(sub=cls("Finger"), sup=part_of("Hand")))
+
+    assert built.to_edge_list() == [(iri("Finger"), "subClassOf", iri("BodyPart"))]
+
+
+def test_to_networkx(built, iri):
+    nx = pytest.importorskip("networkx")
+
+    graph = built.to_networkx()
+    assert isinstance(graph, nx.DiGraph)
+    assert graph.edges[iri("Finger"), iri("Hand")]["predicate"] == iri("part_of")
+
+    multi = built.to_networkx(multi=True)
+    assert isinstance(multi, nx.MultiDiGraph)
+    assert multi.has_edge(iri("Finger"), iri("BodyPart"), key="subClassOf")
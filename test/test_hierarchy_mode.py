@@ -0,0 +1,62 @@
+import warnings
+
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+PART_OF = "https://example.com/part_of"
+
+
+@pytest.fixture
+def built(onto, cls):
+    onto.add_axiom(EquivalentClasses([cls("A"), cls("B")]))
+    onto.add_axiom(EquivalentClasses([
+        cls("C"),
+        ObjectIntersectionOf([cls("B"), ObjectSomeValuesFrom(ObjectProperty(IRI.parse(PART_OF)), cls("D"))]),
+    ]))
+    onto.add_axiom(SubClassOf(sub=cls("D"), sup=ObjectIntersectionOf([cls("A"), cls("B")])))
+    return onto
+
+
+def test_asserted_mode_ignores_equivalents(built, iri):
+    assert built.get_hierarchy_mode() == "asserted"
+    assert built.get_superclasses(iri("C")) == set()
+    assert built.get_superclasses(iri("A")) == set()
+
+
+def test_equivalents_mode_uses_named_equivalents_and_conjuncts(built, iri):
+    built.set_hierarchy_mode("equivalents")
+
+    assert built.get_superclasses(iri("A")) == {iri("B")}
+    assert built.get_superclasses(iri("B")) == {iri("A")}
+    assert built.get_superclasses(iri("C")) == {iri("B")}
+    assert built.get_superclasses(iri("D")) == {iri("A"), iri("B")}
+    assert built.get_subclasses(iri("B")) == {iri("A"), iri("C"), iri("D")}
+
+
+def test_mode_switch_rebuilds_hierarchy(built, iri):
+    built.set_hierarchy_mode("equivalents")
+    built.set_hierarchy_mode("asserted")
+
+    assert built.get_superclasses(iri("C")) == set()
+
+
+def test_new_axioms_follow_mode(onto, iri, cls):
+    onto.set_hierarchy_mode("equivalents")
+    onto.add_axiom(EquivalentClasses([cls("C"), ObjectIntersectionOf([cls("A"), cls("B")])]))
+
+    assert onto.get_superclasses(iri("C")) == {iri("A"), iri("B")}
+
+
+def test_equivalent_classes_are_no_cycle(built, iri):
+    built.set_hierarchy_mode("equivalents")
+
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        assert pyhornedowl.get_ancestors(built, iri("A")) == {iri("B")}
+
+
+def test_unknown_mode(onto):
+    with pytest.raises(ValueError):
+        onto.set_hierarchy_mode("inferred")
@@ -0,0 +1,51 @@
+import pytest
+
+from pyhornedowl.model import *
+
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+@pytest.fixture
+def ind(iri):
+    def ind(name):
+        return NamedIndividual(IRI.parse(iri(name)))
+    return ind
+
+
+@pytest.fixture
+def built(onto, iri, cls, ind):
+    knows = ObjectProperty(IRI.parse(iri("knows")))
+    onto.add_axiom(SubClassOf(sub=cls("Student"), sup=cls("Person")))
+    onto.add_axiom(DeclareNamedIndividual(ind("carol")))
+    onto.add_axiom(ClassAssertion(cls("Person"), ind("alice")))
+    onto.add_axiom(ClassAssertion(cls("Student"), ind("bob")))
+    onto.add_axiom(ObjectPropertyAssertion(knows, ind("alice"), ind("bob")))
+    onto.add_axiom(ObjectPropertyAssertion(InverseObjectProperty(knows), ind("alice"), ind("carol")))
+    onto.add_axiom(DataPropertyAssertion(
+        DataProperty(IRI.parse(iri("has_age"))), ind("alice"), DatatypeLiteral("42", IRI.parse(XSD_INTEGER))))
+    return onto
+
+
+def test_get_individuals_includes_undeclared(built, iri):
+    assert built.get_individuals() == {iri("alice"), iri("bob"), iri("carol")}
+
+
+def test_get_types(built, iri):
+    assert built.get_types(iri("alice")) == {iri("Person")}
+    assert built.get_types(iri("carol")) == set()
+
+
+def test_get_instances(built, iri):
+    assert built.get_instances(iri("Person")) == {iri("alice")}
+    assert built.get_instances(iri("Person"), direct=False) == {iri("alice"), iri("bob")}
+
+
+def test_object_property_values_follow_inverses(built, iri):
+    assert built.get_object_property_values(iri("alice"), iri("knows")) == {iri("bob")}
+    assert built.get_object_property_values(iri("carol"), iri("knows")) == {iri("alice")}
+
+
+def test_data_property_values(built, iri, freeze):
+    assert freeze(built.get_data_property_values(iri("alice"), iri("has_age"))) == \
+        freeze([DatatypeLiteral("42", IRI.parse(XSD_INTEGER))])
+    assert built.get_data_property_values(iri("bob"), iri("has_age")) == []
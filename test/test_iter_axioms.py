@@ -0,0 +1,77 @@
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def subclass(cls):
+    def subclass(n):
+        return SubClassOf(sub=cls(f"C{n + 1}"), sup=cls(f"C{n}"))
+    return subclass
+
+
+@pytest.fixture
+def built(onto, subclass):
+    """Returns an ontology with a subclass chain of `n` axioms."""
+    def built(n):
+        onto.add_axioms(subclass(i) for i in range(n))
+        return onto
+    return built
+
+
+def test_iter_axioms_yields_every_axiom(built, freeze):
+    onto = built(3000)
+
+    axioms = list(onto.iter_axioms())
+
+    assert len(axioms) == 3000
+    assert set(freeze(axioms)) == set(freeze(onto.get_axioms()))
+
+
+def test_iter_axioms_length_hint(built):
+    it = built(10).iter_axioms()
+
+    assert it.__length_hint__() == 10
+    next(it)
+    assert it.__length_hint__() == 9
+
+
+def test_iter_axioms_for_iri(built, iri, subclass, freeze):
+    onto = built(10)
+
+    axioms = {freeze(aax.axiom) for aax in onto.iter_axioms_for_iri(iri("C5"))}
+
+    assert axioms == {freeze(subclass(4)), freeze(subclass(5))}
+    assert list(onto.iter_axioms_for_iri(iri("missing"))) == []
+
+
+def test_iterator_outlives_ontology_reference(subclass):
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.add_axioms(subclass(i) for i in range(5))
+    it = onto.iter_axioms()
+    del onto
+
+    assert len(list(it)) == 5
+
+
+def test_changing_ontology_while_iterating_raises(built, cls):
+    onto = built(10)
+    it = onto.iter_axioms()
+    next(it)
+
+    onto.add_axiom(SubClassOf(sub=cls("C100"), sup=cls("C0")))
+
+    with pytest.raises(RuntimeError):
+        next(it)
+
+
+def test_removing_axioms_while_iterating_raises(built, iri, subclass):
+    onto = built(10)
+    it = onto.iter_axioms_for_iri(iri("C5"))
+    next(it)
+
+    onto.remove_axiom(subclass(9))
+
+    with pytest.raises(RuntimeError):
+        next(it)
@@ -0,0 +1,66 @@
+import json
+
+import pytest
+
+from pyhornedowl.model import *
+
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+@pytest.fixture
+def restriction(iri, cls):
+    return SubClassOf(
+        sub=cls("A"),
+        sup=ObjectIntersectionOf([
+            cls("B"),
+            ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls("C")),
+            DataSomeValuesFrom(
+                DataProperty(IRI.parse(iri("size"))),
+                DatatypeRestriction(Datatype(IRI.parse(XSD_INTEGER)),
+                                    [FacetRestriction(Facet.MinInclusive,
+                                                      DatatypeLiteral("1", IRI.parse(XSD_INTEGER)))])),
+        ]))
+
+
+def test_entity_schema(iri, cls):
+    assert cls("A").to_dict() == {"type": "Class", "first": iri("A")}
+
+
+def test_tagged_union_schema(iri, cls):
+    value = ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("r"))), cls("B")).to_dict()
+
+    assert value == {
+        "type": "ObjectSomeValuesFrom",
+        "ope": {"type": "ObjectProperty", "first": iri("r")},
+        "bce": {"type": "Class", "first": iri("B")},
+    }
+
+
+def test_dict_roundtrip(restriction):
+    assert SubClassOf.from_dict(restriction.to_dict()) == restriction
+
+
+def test_json_roundtrip(restriction):
+    text = restriction.to_json()
+
+    assert json.loads(text) == restriction.to_dict()
+    assert SubClassOf.from_json(text) == restriction
+
+
+def test_annotated_axiom_roundtrip(iri, restriction, annotation):
+    aax = AnnotatedAxiom(restriction, {annotation(iri("note"), LanguageLiteral("x", "en"))})
+
+    assert AnnotatedAxiom.from_json(aax.to_json()) == aax
+
+
+def test_wrong_type_is_rejected(cls):
+    with pytest.raises(TypeError):
+        SubClassOf.from_dict(cls("A").to_dict())
+
+
+def test_missing_field_is_rejected(restriction):
+    value = restriction.to_dict()
+    del value["sup"]
+
+    with pytest.raises(KeyError):
+        SubClassOf.from_dict(value)
@@ -0,0 +1,57 @@
+import pytest
+
+from pyhornedowl.model import *
+
+
+def test_iri_equality_and_hash(iri):
+    assert IRI.parse(iri("A")) == IRI.parse(iri("A"))
+    assert IRI.parse(iri("A")) != IRI.parse(iri("B"))
+    assert hash(IRI.parse(iri("A"))) == hash(IRI.parse(iri("A")))
+    assert len({IRI.parse(iri("A")), IRI.parse(iri("A"))}) == 1
+
+
+def test_iri_ordering(iri):
+    assert IRI.parse(iri("A")) < IRI.parse(iri("B"))
+    assert sorted([IRI.parse(iri("B")), IRI.parse(iri("A"))]) == [IRI.parse(iri("A")), IRI.parse(iri("B"))]
+
+
+def test_named_entity_equality(iri, cls):
+    assert cls("A") == cls("A")
+    assert cls("A") != cls("B")
+    assert cls("A") != ObjectProperty(IRI.parse(iri("A")))
+    assert cls("A") != iri("A")
+
+
+def test_nested_axiom_equality_and_hash(iri, cls):
+    def axiom():
+        return SubClassOf(sub=cls("A"), sup=ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("r"))), cls("B")))
+
+    assert axiom() == axiom()
+    assert hash(axiom()) == hash(axiom())
+    assert axiom() != SubClassOf(sub=cls("A"), sup=cls("B"))
+
+
+def test_model_objects_in_sets_and_dicts(cls):
+    counts = {}
+    for c in [cls("A"), cls("B"), cls("A")]:
+        counts[c] = counts.get(c, 0) + 1
+
+    assert counts == {cls("A"): 2, cls("B"): 1}
+    assert {SubClassOf(sub=cls("A"), sup=cls("B")), SubClassOf(sub=cls("A"), sup=cls("B"))} == {
+        SubClassOf(sub=cls("A"), sup=cls("B"))}
+
+
+def test_ordering(cls):
+    assert cls("A") < cls("B") <= cls("B")
+    assert sorted([cls("C"), cls("A"), cls("B")]) == [cls("A"), cls("B"), cls("C")]
+
+
+def test_ordering_different_types_raises(iri, cls):
+    with pytest.raises(TypeError):
+        cls("A") < ObjectProperty(IRI.parse(iri("A")))
+
+
+def test_literal_equality():
+    assert SimpleLiteral("a") == SimpleLiteral("a")
+    assert LanguageLiteral("a", "en") != LanguageLiteral("a", "de")
+    assert hash(LanguageLiteral("a", "en")) == hash(LanguageLiteral("a", "en"))
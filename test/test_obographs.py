@@ -0,0 +1,105 @@
+import json
+
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+DEFINITION = "http://purl.obolibrary.org/obo/IAO_0000115"
+EXACT_SYNONYM = "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym"
+HAS_DB_XREF = "http://www.geneontology.org/formats/oboInOwl#hasDbXref"
+
+
+@pytest.fixture
+def part_of(iri, cls):
+    def part_of(name):
+        return ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls(name))
+    return part_of
+
+
+@pytest.fixture
+def built(onto, iri, cls, annotation, part_of):
+    def assertion(property, value):
+        return AnnotationAssertion(IRI.parse(iri("A")), annotation(property, SimpleLiteral(value)))
+
+    def xref(value):
+        return annotation(HAS_DB_XREF, SimpleLiteral(value))
+
+    onto.set_iri(iri("onto"))
+    for name in ["A", "B", "C", "D"]:
+        onto.add_axiom(DeclareClass(cls(name)))
+    onto.add_axiom(DeclareObjectProperty(ObjectProperty(IRI.parse(iri("part_of")))))
+    onto.add_axiom(assertion(LABEL, "a"))
+    onto.add_axiom(assertion(DEFINITION, "An A."), {xref("PMID:1")})
+    onto.add_axiom(assertion(EXACT_SYNONYM, "alpha"), {xref("PMID:2")})
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")))
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=part_of("C")))
+    onto.add_axiom(EquivalentClasses([cls("D"), ObjectIntersectionOf([cls("B"), part_of("C")])]))
+    return onto
+
+
+def test_export_document(built, iri, tmp_path):
+    path = tmp_path / "onto.json"
+    built.save_to_file(str(path), format="obographs")
+
+    [graph] = json.loads(path.read_text())["graphs"]
+    assert graph["id"] == iri("onto")
+
+    node = next(n for n in graph["nodes"] if n["id"] == iri("A"))
+    assert node["type"] == "CLASS"
+    assert node["lbl"] == "a"
+    assert node["meta"]["definition"] == {"val": "An A.", "xrefs": ["PMID:1"]}
+    assert node["meta"]["synonyms"] == [{"pred": "hasExactSynonym", "val": "alpha", "xrefs": ["PMID:2"]}]
+
+    edges = {(e["sub"], e["pred"], e["obj"]) for e in graph["edges"]}
+    assert edges == {(iri("A"), "is_a", iri("B")), (iri("A"), iri("part_of"), iri("C"))}
+
+    assert graph["logicalDefinitionAxioms"] == [{
+        "definedClassId": iri("D"),
+        "genusIds": [iri("B")],
+        "restrictions": [{"propertyId": iri("part_of"), "fillerId": iri("C")}],
+    }]
+
+
+def test_roundtrip(built, iri, tmp_path):
+    path = tmp_path / "onto.json"
+    built.save_to_file(str(path))
+
+    loaded = pyhornedowl.open_ontology(str(path))
+
+    assert set(loaded.get_axioms()) == set(built.get_axioms())
+    assert loaded.get_iri() == iri("onto")
+    assert loaded.get_iri_for_label("a") == iri("A")
+    assert loaded.get_superclasses(iri("A")) == {iri("B")}
+
+
+def test_open_from_text(iri):
+    document = json.dumps({"graphs": [{
+        "nodes": [{"id": iri("A"), "type": "CLASS", "lbl": "a"}, {"id": iri("B"), "type": "CLASS"}],
+        "edges": [{"sub": iri("A"), "pred": "is_a", "obj": iri("B")}],
+    }]})
+
+    onto = pyhornedowl.open_ontology(document)
+
+    assert onto.get_iri_for_label("a") == iri("A")
+    assert onto.get_superclasses(iri("A")) == {iri("B")}
+
+
+def test_export_is_sorted(built, cls, part_of, tmp_path):
+    built.add_axiom(SubClassOf(sub=cls("C"), sup=cls("B")))
+    built.add_axiom(EquivalentClasses([cls("C"), ObjectIntersectionOf([cls("A"), part_of("D")])]))
+    path = tmp_path / "onto.json"
+    built.save_to_file(str(path), format="obographs")
+
+    [graph] = json.loads(path.read_text())["graphs"]
+    for key in ["edges", "logicalDefinitionAxioms"]:
+        assert graph[key] == sorted(graph[key], key=lambda value: json.dumps(value, sort_keys=True, separators=(",", ":")))
+
+
+def test_unknown_format_is_rejected(built, tmp_path):
+    path = tmp_path / "onto.txt"
+
+    with pytest.raises(ValueError):
+        built.save_to_file(str(path), format="turtle")
+    assert not path.exists()
@@ -0,0 +1,77 @@
+import pytest
+
+from pyhornedowl.model import *
+
+LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+REPLACED_BY = "http://purl.obolibrary.org/obo/IAO_0100001"
+REASON = "http://purl.obolibrary.org/obo/IAO_0000231"
+CONSIDER = "http://www.geneontology.org/formats/oboInOwl#consider"
+OUT_OF_SCOPE = "http://purl.obolibrary.org/obo/IAO_0000227"
+
+
+@pytest.fixture
+def built(onto, iri, cls, annotation):
+    onto.add_axiom(DeclareClass(cls("A")))
+    onto.set_label(iri("A"), "a")
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")))
+    onto.add_axiom(SubClassOf(sub=cls("C"), sup=cls("B")), {annotation(SEE_ALSO, IRI.parse(iri("A")))})
+    return onto
+
+
+@pytest.fixture
+def values(built, freeze):
+    """Returns the annotation values of a term for a property as frozen model objects."""
+    def values(iri, property):
+        return [
+            freeze(aax.axiom.ann.av)
+            for aax in built.get_axioms_for_iri(iri)
+            if isinstance(aax.axiom, AnnotationAssertion) and str(aax.axiom.ann.ap.first) == property
+        ]
+    return values
+
+
+def test_obsolete_marks_and_relabels(built, iri):
+    built.obsolete(iri("A"))
+
+    assert built.get_obsolete_terms() == {iri("A")}
+    assert built.get_annotations(iri("A"), LABEL) == ["obsolete a"]
+
+
+def test_obsolete_twice_keeps_single_prefix(built, iri):
+    built.obsolete(iri("A"))
+    built.obsolete(iri("A"))
+
+    assert built.get_annotations(iri("A"), LABEL) == ["obsolete a"]
+
+
+def test_obsolete_removes_only_logical_axioms_using_the_term(built, iri, cls, freeze):
+    removed = built.obsolete(iri("A"))
+
+    assert [freeze(aax.axiom) for aax in removed] == [freeze(SubClassOf(sub=cls("A"), sup=cls("B")))]
+    assert built.get_superclasses(iri("A")) == set()
+    assert built.get_superclasses(iri("C")) == {iri("B")}
+
+
+def test_replacement_terms_and_reason_iri(built, iri, freeze, values):
+    built.add_prefix_mapping("ex", iri(""))
+    built.obsolete(iri("A"), replaced_by="ex:B", consider=[iri("C")], reason="IAO:0000227")
+
+    assert values(iri("A"), REPLACED_BY) == [freeze(IRI.parse(iri("B")))]
+    assert values(iri("A"), CONSIDER) == [freeze(IRI.parse(iri("C")))]
+    assert values(iri("A"), REASON) == [freeze(IRI.parse(OUT_OF_SCOPE))]
+
+
+def test_free_text_reason_is_literal(built, iri, freeze, values):
+    built.obsolete(iri("A"), reason="merged into B")
+
+    assert values(iri("A"), REASON) == [freeze(SimpleLiteral("merged into B"))]
+
+
+def test_unmapped_prefix_is_rejected(built, iri, values):
+    with pytest.raises(ValueError):
+        built.obsolete(iri("A"), replaced_by="unknown:B")
+
+    assert built.get_obsolete_terms() == set()
+    assert values(iri("A"), REPLACED_BY) == []
+    assert built.get_superclasses(iri("A")) == {iri("B")}
@@ -0,0 +1,45 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = "https://example.com/onto.owl"
+VERSION = "https://example.com/2024-01-01/onto.owl"
+TITLE = "http://purl.org/dc/terms/title"
+LICENSE = "http://purl.org/dc/terms/license"
+
+
+def test_set_iri_and_version_iri(onto):
+    assert onto.get_iri() is None
+
+    onto.set_iri(ONTOLOGY)
+    onto.set_version_iri(VERSION)
+    assert onto.get_iri() == ONTOLOGY
+    assert onto.get_version_iri() == VERSION
+
+    onto.set_version_iri(None)
+    assert onto.get_version_iri() is None
+
+
+def test_set_ontology_annotations_replaces(onto, annotation, freeze):
+    onto.set_ontology_annotations([annotation(TITLE, SimpleLiteral("Old title"))])
+
+    new = [
+        annotation(TITLE, SimpleLiteral("New title")),
+        annotation(LICENSE, IRI.parse("https://creativecommons.org/licenses/by/4.0/")),
+    ]
+    onto.set_ontology_annotations(new)
+
+    assert freeze(set(onto.get_ontology_annotations())) == freeze(set(new))
+
+
+def test_save_to_file_keeps_ids_and_annotations(onto, annotation, freeze, tmp_path):
+    onto.set_iri(ONTOLOGY)
+    onto.set_version_iri(VERSION)
+    onto.set_ontology_annotations([annotation(TITLE, SimpleLiteral("Title"))])
+
+    path = str(tmp_path / "onto.owx")
+    onto.save_to_file(path)
+    loaded = pyhornedowl.open_ontology(path)
+
+    assert loaded.get_iri() == ONTOLOGY
+    assert loaded.get_version_iri() == VERSION
+    assert freeze(loaded.get_ontology_annotations()) == freeze([annotation(TITLE, SimpleLiteral("Title"))])
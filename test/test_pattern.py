@@ -0,0 +1,101 @@
+import pytest
+
+from pyhornedowl import Pattern, Var
+from pyhornedowl.model import *
+
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+
+
+@pytest.fixture
+def op(iri):
+    def op(name):
+        return ObjectProperty(IRI.parse(iri(name)))
+    return op
+
+
+@pytest.fixture
+def part_of(op):
+    def part_of(filler):
+        return ObjectSomeValuesFrom(op("part_of"), filler)
+    return part_of
+
+
+@pytest.fixture
+def built(onto, cls, op, part_of):
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=part_of(cls("B"))))
+    onto.add_axiom(SubClassOf(sub=cls("B"), sup=cls("C")))
+    onto.add_axiom(EquivalentClasses([cls("A"), ObjectIntersectionOf([cls("B"), part_of(cls("C"))])]))
+    onto.add_axiom(DisjointClasses([cls("A"), cls("C")]))
+    onto.add_axiom(SubObjectPropertyOf(sub=[op("part_of"), op("located_in")], sup=op("located_in")))
+    return onto
+
+
+def test_find_restrictions(built, cls, op, freeze):
+    pattern = Pattern(SubClassOf, sub=Var("x"), sup=Pattern(ObjectSomeValuesFrom, op("part_of"), Var("y")))
+
+    assert freeze(built.match_axioms(pattern)) == freeze([{"x": cls("A"), "y": cls("B")}])
+
+
+def test_unordered_expressions_match_in_any_order(built, cls, op, freeze):
+    pattern = Pattern(EquivalentClasses, [
+        Pattern(ObjectIntersectionOf, [Pattern(ObjectSomeValuesFrom, op("part_of"), Var("y")), Var("genus")]),
+        Var("x"),
+    ])
+
+    assert freeze(built.match_axioms(pattern)) == freeze([{"genus": cls("B"), "y": cls("C"), "x": cls("A")}])
+
+
+def test_unordered_matching_backtracks(built, cls, freeze):
+    # Binding x to A first would leave no expression for the fixed element
+    pattern = Pattern(DisjointClasses, [Var("x"), cls("A")])
+
+    assert freeze(built.match_axioms(pattern)) == freeze([{"x": cls("C")}])
+
+
+def test_unordered_elements_match_different_expressions(built, cls):
+    assert built.match_axioms(Pattern(DisjointClasses, [Var("x"), Var("x")])) == []
+    assert built.match_axioms(Pattern(DisjointClasses, [cls("A"), cls("A")])) == []
+
+
+def test_model_object_pattern_ignores_order(built, cls):
+    assert built.match_axioms(DisjointClasses([cls("C"), cls("A")])) == [{}]
+
+
+def test_property_chains_stay_ordered(built, op):
+    pattern = Pattern(SubObjectPropertyOf, sub=[op("located_in"), op("part_of")])
+
+    assert built.match_axioms(pattern) == []
+    assert len(built.match_axioms(Pattern(SubObjectPropertyOf, sub=[op("part_of"), Var("p")]))) == 1
+
+
+def test_annotated_axiom_pattern(onto, cls, annotation, freeze):
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")), {annotation(COMMENT, SimpleLiteral("note"))})
+
+    pattern = Pattern(AnnotatedAxiom, Var("axiom"), [Pattern(Annotation, AnnotationProperty(IRI.parse(COMMENT)), Var("value"))])
+
+    assert freeze(onto.match_axioms(pattern)) == \
+        freeze([{"axiom": SubClassOf(sub=cls("A"), sup=cls("B")), "value": SimpleLiteral("note")}])
+
+
+def test_unordered_matches_yield_every_binding(built, cls, freeze):
+    pattern = Pattern(DisjointClasses, [Var("x"), Var("y")])
+
+    assert freeze(built.match_axioms(pattern)) == freeze([
+        {"x": cls("A"), "y": cls("C")},
+        {"x": cls("C"), "y": cls("A")},
+    ])
+
+
+def test_constructors_with_variables_build_patterns(built, cls, op, freeze):
+    pattern = SubClassOf(Var("x"), ObjectSomeValuesFrom(op("part_of"), Var("y")))
+
+    assert isinstance(pattern, Pattern)
+    assert freeze(built.match_axioms(pattern)) == freeze([{"x": cls("A"), "y": cls("B")}])
+    assert freeze(built.match_axioms(DisjointClasses([Var("x"), cls("A")]))) == freeze([{"x": cls("C")}])
+
+
+def test_constructors_without_variables_build_model_objects(cls):
+    axiom = SubClassOf(sub=cls("A"), sup=cls("B"))
+
+    assert isinstance(axiom, SubClassOf)
+    assert not isinstance(axiom, Pattern)
@@ -0,0 +1,76 @@
+import copy
+import pickle
+
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def axiom(cls):
+    return SubClassOf(sub=cls("A"), sup=ObjectIntersectionOf([cls("B"), cls("C")]))
+
+
+@pytest.fixture
+def built(onto, iri, axiom):
+    onto.set_iri(iri("onto"))
+    onto.add_axiom(axiom)
+    onto.set_label(iri("A"), "a")
+    onto.set_hierarchy_mode("equivalents")
+    return onto
+
+
+@pytest.fixture
+def assert_same(iri):
+    def assert_same(restored, onto):
+        assert isinstance(restored, pyhornedowl.PyIndexedOntology)
+        assert restored is not onto
+        assert set(restored.get_axioms()) == set(onto.get_axioms())
+        assert restored.get_iri() == iri("onto")
+        assert restored.get_iri_for_label("a") == iri("A")
+        assert restored.get_hierarchy_mode() == "equivalents"
+        assert restored.get_superclasses(iri("A")) == {iri("B"), iri("C")}
+    return assert_same
+
+
+def test_model_objects_pickle(iri, cls, axiom):
+    for obj in [IRI.parse(iri("A")), cls("A"), axiom, AnnotatedAxiom(axiom, set()), Facet.MinLength,
+                LanguageLiteral("a", "en")]:
+        assert pickle.loads(pickle.dumps(obj)) == obj
+
+
+def test_model_constructors_pickle():
+    assert pickle.loads(pickle.dumps(SubClassOf)) is SubClassOf
+
+
+def test_model_objects_copy(iri, cls, axiom):
+    for obj in [IRI.parse(iri("A")), cls("A"), axiom, Facet.Pattern]:
+        assert copy.copy(obj) == obj
+        assert copy.deepcopy(obj) == obj
+
+    deep = copy.deepcopy(axiom)
+    deep.sub = cls("C")
+    assert axiom.sub == cls("A")
+
+
+def test_ontology_class_module():
+    assert pyhornedowl.PyIndexedOntology.__module__ == "pyhornedowl"
+
+
+def test_ontology_pickle(built, assert_same):
+    assert_same(pickle.loads(pickle.dumps(built)), built)
+
+
+def test_ontology_copy(built, assert_same):
+    assert_same(copy.copy(built), built)
+    assert_same(copy.deepcopy(built), built)
+
+
+def test_ontology_copy_is_independent(built, iri, axiom):
+    copied = copy.deepcopy(built)
+
+    copied.remove_axiom(axiom)
+
+    assert built.get_superclasses(iri("A")) == {iri("B"), iri("C")}
+    assert copied.get_superclasses(iri("A")) == set()
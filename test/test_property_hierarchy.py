@@ -0,0 +1,55 @@
+import pytest
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def op(iri):
+    def op(name):
+        return ObjectProperty(IRI.parse(iri(name)))
+    return op
+
+
+@pytest.fixture
+def built(onto, iri, op):
+    onto.add_axiom(SubObjectPropertyOf(sub=op("direct_part_of"), sup=op("part_of")))
+    onto.add_axiom(SubObjectPropertyOf(sub=op("part_of"), sup=op("overlaps")))
+    onto.add_axiom(InverseObjectProperties(op("part_of"), op("has_part")))
+    onto.add_axiom(SubObjectPropertyOf(sub=[op("part_of"), op("located_in")], sup=op("located_in")))
+    onto.add_axiom(SubDataPropertyOf(
+        sub=DataProperty(IRI.parse(iri("has_age_in_years"))), sup=DataProperty(IRI.parse(iri("has_age")))))
+    onto.add_axiom(SubAnnotationPropertyOf(
+        sub=AnnotationProperty(IRI.parse(iri("exact_synonym"))), sup=AnnotationProperty(IRI.parse(iri("synonym")))))
+    return onto
+
+
+def test_direct_sub_and_super_properties(built, iri):
+    assert built.get_super_properties(iri("direct_part_of")) == {iri("part_of")}
+    assert built.get_sub_properties(iri("overlaps")) == {iri("part_of")}
+    assert built.get_super_properties(iri("has_age_in_years")) == {iri("has_age")}
+    assert built.get_sub_properties(iri("synonym")) == {iri("exact_synonym")}
+
+
+def test_inverse_in_both_directions(built, iri):
+    assert built.get_inverse(iri("part_of")) == {iri("has_part")}
+    assert built.get_inverse(iri("has_part")) == {iri("part_of")}
+
+
+def test_property_chains(built, iri):
+    assert built.get_property_chains(iri("located_in")) == [[iri("part_of"), iri("located_in")]]
+    assert built.get_property_chains(iri("part_of")) == []
+
+
+def test_transitive_closure(built, iri):
+    assert pyhornedowl.get_property_ancestors(built, iri("direct_part_of")) == {iri("part_of"), iri("overlaps")}
+    assert pyhornedowl.get_property_descendants(built, iri("overlaps")) == {iri("part_of"), iri("direct_part_of")}
+    assert pyhornedowl.get_property_ancestors(built, iri("direct_part_of"), max_depth=1) == {iri("part_of")}
+
+
+def test_removal_updates_indexes(built, iri, op):
+    built.remove_axiom(SubObjectPropertyOf(sub=op("part_of"), sup=op("overlaps")))
+    built.remove_axiom(InverseObjectProperties(op("part_of"), op("has_part")))
+
+    assert built.get_super_properties(iri("part_of")) == set()
+    assert built.get_inverse(iri("has_part")) == set()
@@ -123,10 +123,10 @@ if FULL:
 
 if FULL:
     clssid = onto.get_iri_for_label("caffeine")
-    ancestors = pyhornedowl.get_ancestors(onto,clssid)
+    ancestors = pyhornedowl.get_ancestors(onto,clssid,include_self=True)
     print(f"Got ancestors {ancestors} for class {clssid}")
     print("Getting a whole lot of labels for IRIs")
-    descs = pyhornedowl.get_descendants(onto,onto.get_iri_for_label('carboxylic acid'))
+    descs = pyhornedowl.get_descendants(onto,onto.get_iri_for_label('carboxylic acid'),include_self=True)
     desc_names = [onto.get_annotation(d,RDFSLABEL) for d in descs]
     print(len(desc_names))
 
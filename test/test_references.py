@@ -0,0 +1,58 @@
+import pytest
+
+from pyhornedowl.model import *
+
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+
+
+@pytest.fixture
+def axioms(iri, cls):
+    return {
+        "declaration": DeclareClass(cls("A")),
+        "subclass": SubClassOf(sub=cls("A"), sup=cls("B")),
+        "restriction": SubClassOf(sub=cls("C"), sup=ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls("A"))),
+        "see_also": AnnotationAssertion(IRI.parse(iri("B")), Annotation(AnnotationProperty(IRI.parse(SEE_ALSO)), IRI.parse(iri("A")))),
+    }
+
+
+@pytest.fixture
+def built(onto, iri, axioms):
+    for axiom in axioms.values():
+        onto.add_axiom(axiom)
+    onto.set_label(iri("A"), "a")
+    return onto
+
+
+@pytest.fixture
+def referencing(built, freeze):
+    """Returns the frozen axioms referencing a term, optionally in a role."""
+    def referencing(iri, **kwargs):
+        return {freeze(aax.axiom) for aax in built.get_referencing_axioms(iri, **kwargs)}
+    return referencing
+
+
+def test_subject_role(iri, axioms, referencing, freeze):
+    subject = referencing(iri("A"), role="subject")
+    assert freeze(axioms["declaration"]) in subject
+    assert freeze(axioms["subclass"]) in subject
+    assert freeze(axioms["restriction"]) not in subject
+    assert len(subject) == 3
+
+
+def test_filler_role(iri, axioms, referencing, freeze):
+    assert referencing(iri("A"), role="filler") == {freeze(axioms["restriction"]), freeze(axioms["see_also"])}
+    assert referencing(iri("B"), role="filler") == {freeze(axioms["subclass"])}
+
+
+def test_any_role(built, iri):
+    assert len(built.get_referencing_axioms(iri("A"))) == 5
+
+
+def test_usage_count(built, iri):
+    assert built.usage_count(iri("A")) == 2
+    assert built.usage_count(iri("C")) == 0
+
+
+def test_unknown_role(built, iri):
+    with pytest.raises(ValueError):
+        built.get_referencing_axioms(iri("A"), role="object")
@@ -0,0 +1,72 @@
+import pytest
+
+from pyhornedowl.model import *
+
+
+@pytest.fixture
+def some(iri, cls):
+    def some(property, name):
+        return ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri(property))), cls(name))
+    return some
+
+
+@pytest.fixture
+def built(onto, cls, some):
+    for axiom in [
+        SubClassOf(sub=cls("A"), sup=some("part_of", "B")),
+        SubClassOf(sub=cls("A"), sup=some("has_part", "K")),
+        SubClassOf(sub=cls("C"), sup=ObjectIntersectionOf([cls("B"), some("part_of", "D")])),
+        EquivalentClasses([cls("E"), some("part_of", "F")]),
+        SubClassOf(sub=cls("B"), sup=some("part_of", "G")),
+        SubClassOf(sub=cls("G"), sup=cls("H")),
+        SubClassOf(sub=cls("A2"), sup=cls("A")),
+        EquivalentClasses([cls("X"), ObjectIntersectionOf([cls("A"), cls("Y")])]),
+    ]:
+        onto.add_axiom(axiom)
+    return onto
+
+
+def test_get_related(built, iri):
+    assert built.get_related(iri("A"), iri("part_of")) == {iri("B")}
+    assert built.get_related(iri("C"), iri("part_of")) == {iri("D")}
+    assert built.get_related(iri("E"), iri("part_of")) == {iri("F")}
+    assert built.get_related(iri("A2"), iri("part_of")) == set()
+    assert built.get_related(iri("A"), iri("unknown")) == set()
+
+
+def test_get_relations(built, iri):
+    assert built.get_relations(iri("A")) == {iri("part_of"): {iri("B")}, iri("has_part"): {iri("K")}}
+    assert built.get_relations(iri("H")) == {}
+
+
+def test_relations_follow_removal(built, iri, cls, some):
+    built.remove_axiom(SubClassOf(sub=cls("A"), sup=some("part_of", "B")))
+
+    assert built.get_relations(iri("A")) == {iri("has_part"): {iri("K")}}
+
+
+def test_related_transitive_without_is_a(built, iri):
+    assert built.get_related_transitive(iri("A"), iri("part_of"), include_is_a=False) == {iri("B"), iri("G")}
+    assert built.get_related_transitive(iri("A2"), iri("part_of"), include_is_a=False) == set()
+
+
+def test_related_transitive_with_is_a(built, iri):
+    assert built.get_related_transitive(iri("A"), iri("part_of")) == {iri("B"), iri("G"), iri("H")}
+    assert built.get_related_transitive(iri("A2"), iri("part_of")) == {iri("B"), iri("G"), iri("H")}
+    assert built.get_related_transitive(iri("H"), iri("part_of")) == set()
+
+
+def test_related_transitive_depends_on_hierarchy_mode(built, iri):
+    assert built.get_related_transitive(iri("X"), iri("part_of")) == set()
+
+    built.set_hierarchy_mode("equivalents")
+
+    assert built.get_related_transitive(iri("X"), iri("part_of")) == {iri("B"), iri("G"), iri("H")}
+    assert built.get_related_transitive(iri("X"), iri("part_of"), include_is_a=False) == set()
+
+
+def test_edge_list_includes_derived_relations(built, iri):
+    edges = set(built.to_edge_list(relations=[iri("part_of")]))
+
+    assert (iri("C"), iri("part_of"), iri("D")) in edges
+    assert (iri("E"), iri("part_of"), iri("F")) in edges
@@ -0,0 +1,51 @@
+import pytest
+
+from pyhornedowl.model import *
+
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+
+
+@pytest.fixture
+def comment(annotation):
+    def comment(text):
+        return annotation(COMMENT, SimpleLiteral(text))
+    return comment
+
+
+@pytest.fixture
+def axiom(cls):
+    return SubClassOf(sub=cls("A"), sup=cls("B"))
+
+
+@pytest.fixture
+def built(onto, axiom, comment):
+    onto.add_axiom(axiom)
+    onto.add_axiom(axiom, {comment("first")})
+    onto.add_axiom(axiom, {comment("second")})
+    return onto
+
+
+def test_remove_axiom_removes_all_annotated_variants(built, iri, axiom):
+    assert built.remove_axiom(axiom) == 3
+    assert built.get_axioms() == []
+    assert built.get_superclasses(iri("A")) == set()
+
+
+def test_remove_annotated_axiom_matches_annotations(built, iri, axiom, comment):
+    assert built.remove_axiom(AnnotatedAxiom(axiom, {comment("first")})) == 1
+    assert built.remove_axiom(AnnotatedAxiom(axiom, {comment("other")})) == 0
+    assert len(built.get_axioms()) == 2
+    assert built.get_superclasses(iri("A")) == {iri("B")}
+
+
+def test_hierarchy_kept_until_last_variant_is_removed(built, iri, axiom, comment):
+    built.remove_axiom(AnnotatedAxiom(axiom, set()))
+    built.remove_axiom(AnnotatedAxiom(axiom, {comment("first")}))
+    assert built.get_superclasses(iri("A")) == {iri("B")}
+
+    built.remove_axiom(AnnotatedAxiom(axiom, {comment("second")}))
+    assert built.get_superclasses(iri("A")) == set()
+
+
+def test_remove_missing_axiom_returns_zero(onto, axiom):
+    assert onto.remove_axiom(axiom) == 0
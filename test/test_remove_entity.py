@@ -0,0 +1,64 @@
+import pytest
+
+from pyhornedowl.model import *
+
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+
+
+@pytest.fixture
+def see_also(iri, annotation):
+    def see_also(name):
+        return annotation(SEE_ALSO, IRI.parse(iri(name)))
+    return see_also
+
+
+@pytest.fixture
+def axioms(iri, cls, see_also):
+    return {
+        "declaration": DeclareClass(cls("A")),
+        "subclass": SubClassOf(sub=cls("A"), sup=cls("B")),
+        "restriction": SubClassOf(sub=cls("C"), sup=ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls("A"))),
+        "pointer": AnnotationAssertion(IRI.parse(iri("B")), see_also("A")),
+        "annotated": SubClassOf(sub=cls("C"), sup=cls("B")),
+    }
+
+
+@pytest.fixture
+def built(onto, iri, axioms, see_also):
+    onto.add_axiom(axioms["declaration"], {see_also("B")})
+    onto.set_label(iri("A"), "a")
+    for name in ["subclass", "restriction", "pointer"]:
+        onto.add_axiom(axioms[name])
+    onto.add_axiom(axioms["annotated"], {see_also("A")})
+    return onto
+
+
+def frozen_axioms(aaxs, freeze):
+    return {freeze(aax.axiom) for aax in aaxs}
+
+
+def test_remove_without_cascade(built, iri, axioms, freeze):
+    removed = frozen_axioms(built.remove_entity(iri("A"), cascade=False), freeze)
+
+    assert len(removed) == 2
+    assert freeze(axioms["declaration"]) in removed
+    assert built.get_iri_for_label("a") is None
+    assert built.get_superclasses(iri("A")) == {iri("B")}
+
+
+def test_cascade_removes_logical_axioms(built, iri, axioms, freeze):
+    removed = frozen_axioms(built.remove_entity(iri("A")), freeze)
+
+    assert len(removed) == 4
+    assert {freeze(axioms[name]) for name in ["declaration", "subclass", "restriction"]} <= removed
+    assert built.get_superclasses(iri("A")) == set()
+    assert built.get_subclasses(iri("B")) == {iri("C")}
+
+
+def test_cascade_keeps_references_in_annotations(built, iri, axioms, freeze):
+    built.remove_entity(iri("A"))
+
+    remaining = frozen_axioms(built.get_axioms(), freeze)
+    assert freeze(axioms["pointer"]) in remaining
+    assert freeze(axioms["annotated"]) in remaining
+    assert len(remaining) == 2
@@ -0,0 +1,79 @@
+import pytest
+
+from pyhornedowl.model import *
+
+OLD = "https://old.example.com/"
+NEW = "https://new.example.com/"
+LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+
+
+def cls(iri):
+    return Class(IRI.parse(iri))
+
+
+def restriction(prefix):
+    return ObjectSomeValuesFrom(ObjectProperty(IRI.parse(prefix + "part_of")), cls(prefix + "B"))
+
+
+@pytest.fixture
+def built(onto, annotation):
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "A"), sup=restriction(OLD)), {annotation(SEE_ALSO, IRI.parse(OLD + "B"))})
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "A"), sup=cls(OLD + "C")))
+    onto.add_axiom(AnnotationAssertion(IRI.parse(OLD + "C"), annotation(SEE_ALSO, IRI.parse(OLD + "A"))))
+    onto.set_label(OLD + "A", "a")
+    return onto
+
+
+def test_rename_entity_rewrites_every_occurrence(built):
+    assert built.rename_entity(OLD + "A", NEW + "A") == 4
+
+    assert built.get_axioms_for_iri(OLD + "A") == []
+    assert built.get_superclasses(NEW + "A") == {OLD + "C"}
+    assert built.get_iri_for_label("a") == NEW + "A"
+    assert built.get_annotation(NEW + "A", LABEL) == "a"
+
+    see_also = [aax.axiom for aax in built.get_axioms_for_iri(OLD + "C")
+                if isinstance(aax.axiom, AnnotationAssertion)]
+    assert [str(ax.ann.av) for ax in see_also] == [NEW + "A"]
+
+
+def test_rename_entities_in_nested_expressions_and_annotations(built, annotation, freeze):
+    built.rename_entities({OLD + "A": NEW + "A", OLD + "B": NEW + "B", OLD + "part_of": NEW + "part_of"})
+
+    expected = AnnotatedAxiom(
+        SubClassOf(sub=cls(NEW + "A"), sup=restriction(NEW)),
+        {annotation(SEE_ALSO, IRI.parse(NEW + "B"))})
+    assert freeze(expected) in freeze(built.get_axioms())
+    assert built.get_axioms_for_iri(OLD + "B") == []
+    assert built.get_axioms_for_iri(OLD + "part_of") == []
+
+
+def test_rename_to_same_iri_changes_nothing(built):
+    assert built.rename_entity(OLD + "A", OLD + "A") == 0
+    assert len(built.get_axioms()) == 4
+
+
+
+def test_swapping_iris_keeps_every_axiom(onto, freeze):
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "A"), sup=cls(OLD + "B")))
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "B"), sup=cls(OLD + "A")))
+
+    assert onto.rename_entities({OLD + "A": OLD + "B", OLD + "B": OLD + "A"}) == 2
+    assert {freeze(aax.axiom) for aax in onto.get_axioms()} == {
+        freeze(SubClassOf(sub=cls(OLD + "B"), sup=cls(OLD + "A"))),
+        freeze(SubClassOf(sub=cls(OLD + "A"), sup=cls(OLD + "B"))),
+    }
+    assert onto.get_superclasses(OLD + "A") == {OLD + "B"}
+    assert onto.get_superclasses(OLD + "B") == {OLD + "A"}
+
+
+def test_chained_renames_apply_once(onto, freeze):
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "A"), sup=cls(OLD + "B")))
+    onto.add_axiom(SubClassOf(sub=cls(OLD + "B"), sup=cls(OLD + "C")))
+
+    assert onto.rename_entities({OLD + "A": OLD + "B", OLD + "B": OLD + "C"}) == 2
+    assert {freeze(aax.axiom) for aax in onto.get_axioms()} == {
+        freeze(SubClassOf(sub=cls(OLD + "B"), sup=cls(OLD + "C"))),
+        freeze(SubClassOf(sub=cls(OLD + "C"), sup=cls(OLD + "C"))),
+    }
@@ -0,0 +1,62 @@
+import pytest
+
+from pyhornedowl import model
+from pyhornedowl.model import *
+
+
+def roundtrip(obj):
+    return eval(repr(obj), vars(model))
+
+
+@pytest.fixture
+def restriction(iri, cls):
+    return SubClassOf(sub=cls("A"), sup=ObjectSomeValuesFrom(ObjectProperty(IRI.parse(iri("part_of"))), cls("B")))
+
+
+def test_iri_repr(iri):
+    assert repr(IRI.parse(iri("A"))) == f"IRI.parse('{iri('A')}')"
+    assert roundtrip(IRI.parse(iri("A"))) == IRI.parse(iri("A"))
+
+
+def test_constructor_style_repr(iri, cls, restriction):
+    assert repr(cls("A")) == f"Class(IRI.parse('{iri('A')}'))"
+
+    assert repr(restriction).startswith("SubClassOf(")
+    assert roundtrip(restriction) == restriction
+
+
+def test_string_repr_matches_python():
+    for text in ["plain", "it's", 'say "hi"', "both ' and \"", "back\\slash", "line\nbreak\ttab\r",
+                 "bell\x07", "nbsp\xa0", "café 中文", "emoji \U0001F600"]:
+        literal = SimpleLiteral(text)
+        assert repr(literal) == f"SimpleLiteral({text!r})"
+        assert roundtrip(literal) == literal
+
+
+def test_annotated_axiom_repr_roundtrip(iri, cls, annotation):
+    aax = AnnotatedAxiom(SubClassOf(sub=cls("A"), sup=cls("B")), {annotation(iri("note"), LanguageLiteral("x", "en"))})
+
+    assert roundtrip(aax) == aax
+
+
+def test_str_is_compact(iri, cls):
+    a, b = iri("A"), iri("B")
+
+    assert str(cls("A")) == f"<{a}>"
+    assert str(LanguageLiteral("x", "en")) == '"x"@en'
+    assert str(SubClassOf(sub=cls("A"), sup=cls("B"))) in (f"SubClassOf(<{b}>, <{a}>)", f"SubClassOf(<{a}>, <{b}>)")
+
+
+def test_pretty_uses_labels(onto, iri, cls):
+    onto.set_label(iri("A"), "a")
+
+    assert onto.pretty(cls("A")) == "'a'"
+    assert onto.pretty(cls("B")) == f"<{iri('B')}>"
+
+
+def test_pretty_breaks_long_lines(onto, restriction):
+    lines = onto.pretty(restriction, width=40).splitlines()
+
+    assert len(lines) > 1
+    assert lines[0] == "SubClassOf("
+    assert lines[-1] == ")"
@@ -0,0 +1,66 @@
+import pytest
+
+from pyhornedowl.model import *
+
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+XSD_STRING = "http://www.w3.org/2001/XMLSchema#string"
+
+
+@pytest.fixture
+def built(onto, iri, cls, annotation):
+    onto.add_axiom(DeclareClass(cls("A")), {annotation(COMMENT, DatatypeLiteral("declared", IRI.parse(XSD_STRING)))})
+    onto.add_axiom(DeclareDataProperty(DataProperty(IRI.parse(iri("has_age")))))
+    onto.add_axiom(DeclareAnnotationProperty(AnnotationProperty(IRI.parse(iri("synonym")))))
+    onto.add_axiom(DeclareNamedIndividual(NamedIndividual(IRI.parse(iri("alice")))))
+    onto.add_axiom(DeclareDatatype(Datatype(IRI.parse(iri("Age")))))
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")))
+    return onto
+
+
+def test_declared_entities_by_kind(built, iri):
+    assert built.get_classes() == {iri("A")}
+    assert built.get_data_properties() == {iri("has_age")}
+    assert built.get_annotation_properties() == {iri("synonym")}
+    assert built.get_named_individuals() == {iri("alice")}
+    assert built.get_datatypes() == {iri("Age")}
+
+
+def test_declared_signature_ignores_declaration_annotations(built, iri, cls, freeze):
+    assert freeze(built.get_signature(include_undeclared=False)) == freeze([
+        AnnotationProperty(IRI.parse(iri("synonym"))),
+        cls("A"),
+        DataProperty(IRI.parse(iri("has_age"))),
+        Datatype(IRI.parse(iri("Age"))),
+        NamedIndividual(IRI.parse(iri("alice"))),
+    ])
+
+
+def test_signature_includes_undeclared_entities(built, iri, cls, freeze):
+    assert freeze(built.get_signature()) == freeze([
+        AnnotationProperty(IRI.parse(COMMENT)),
+        AnnotationProperty(IRI.parse(iri("synonym"))),
+        cls("A"),
+        cls("B"),
+        DataProperty(IRI.parse(iri("has_age"))),
+        Datatype(IRI.parse(XSD_STRING)),
+        Datatype(IRI.parse(iri("Age"))),
+        NamedIndividual(IRI.parse(iri("alice"))),
+    ])
+
+
+def test_signature_finds_nested_entities(onto, iri, cls, freeze):
+    onto.add_axiom(SubClassOf(
+        sub=cls("A"),
+        sup=ObjectSomeValuesFrom(
+            InverseObjectProperty(ObjectProperty(IRI.parse(iri("part_of")))),
+            DataHasValue(DataProperty(IRI.parse(iri("size"))), DatatypeLiteral("1", IRI.parse(iri("number")))))))
+    onto.add_axiom(ClassAssertion(cls("B"), NamedIndividual(IRI.parse(iri("alice")))))
+
+    assert freeze(onto.get_signature()) == freeze([
+        cls("A"),
+        cls("B"),
+        DataProperty(IRI.parse(iri("size"))),
+        Datatype(IRI.parse(iri("number"))),
+        NamedIndividual(IRI.parse(iri("alice"))),
+        ObjectProperty(IRI.parse(iri("part_of"))),
+    ])
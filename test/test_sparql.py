@@ -0,0 +1,65 @@
+import pytest
+
+from pyhornedowl.model import *
+
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+CLASSES = """
+    SELECT ?cls ?label WHERE {
+        ?cls a owl:Class .
+        OPTIONAL { ?cls rdfs:label ?label }
+    }
+"""
+
+
+@pytest.fixture
+def built(onto, iri, cls):
+    onto.add_axiom(DeclareClass(cls("A")))
+    onto.add_axiom(DeclareClass(cls("B")))
+    onto.set_label(iri("A"), "a")
+    for name, value in [("x", "-10"), ("y", "0"), ("z", "3")]:
+        onto.add_axiom(DataPropertyAssertion(
+            DataProperty(IRI.parse(iri("has_value"))),
+            NamedIndividual(IRI.parse(iri(name))),
+            DatatypeLiteral(value, IRI.parse(XSD_INTEGER))))
+    return onto
+
+
+def test_optional_leaves_variables_unbound(built, iri):
+    assert sorted(built.sparql(CLASSES)) == [(iri("A"), "a"), (iri("B"), None)]
+
+
+def test_filter_with_signed_numbers(built, iri):
+    rows = built.sparql(f"SELECT ?i ?v WHERE {{ ?i <{iri('has_value')}> ?v FILTER(?v > -5) }}")
+    assert sorted(rows) == [(iri("y"), 0), (iri("z"), 3)]
+
+    rows = built.sparql(f"SELECT ?v WHERE {{ ?i <{iri('has_value')}> ?v FILTER(?v >= +3) }}")
+    assert rows == [(3,)]
+
+
+def test_filter_on_strings(built, iri):
+    rows = built.sparql("""
+        SELECT ?cls WHERE {
+            ?cls rdfs:label ?label
+            FILTER(?label = "a")
+        }
+    """)
+    assert rows == [(iri("A"),)]
+
+
+def test_results_follow_changes(built, iri, cls):
+    assert len(built.sparql(CLASSES)) == 2
+
+    built.add_axiom(DeclareClass(cls("C")))
+    assert len(built.sparql(CLASSES)) == 3
+
+    built.remove_axiom(DeclareClass(cls("A")))
+    assert sorted(built.sparql(CLASSES)) == [(iri("B"), None), (iri("C"), None)]
+
+    built.set_label(iri("B"), "b")
+    assert (iri("B"), "b") in built.sparql(CLASSES)
+
+
+def test_invalid_query(built):
+    with pytest.raises(ValueError):
+        built.sparql("SELECT ?x WHERE { ?x ")
@@ -0,0 +1,81 @@
+import csv
+
+import pytest
+
+from pyhornedowl.model import *
+
+DEFINITION = "http://purl.obolibrary.org/obo/IAO_0000115"
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+
+
+@pytest.fixture
+def built(onto, iri, cls, annotation):
+    def assertion(subject, property, value):
+        return AnnotationAssertion(IRI.parse(iri(subject)), annotation(property, value))
+
+    onto.add_prefix_mapping("ex", iri(""))
+    for name in ["A", "B", "C"]:
+        onto.add_axiom(DeclareClass(cls(name)))
+    onto.add_axiom(DeclareAnnotationProperty(AnnotationProperty(IRI.parse(iri("note")))))
+    onto.add_axiom(SubClassOf(sub=cls("A"), sup=cls("B")))
+    onto.set_label(iri("A"), "a")
+    onto.set_label(iri("B"), "b")
+    onto.set_label(iri("C"), "c label")
+    onto.set_label(iri("note"), "note")
+    onto.add_axiom(assertion("A", DEFINITION, SimpleLiteral('An "A", with a comma')))
+    onto.add_axiom(assertion("A", iri("note"), SimpleLiteral("second")))
+    onto.add_axiom(assertion("A", iri("note"), LanguageLiteral("first", "en")))
+    onto.add_axiom(assertion("B", DEFINITION, SimpleLiteral("A B.")),
+                   {annotation(SEE_ALSO, IRI.parse(iri("A")))})
+    return onto
+
+
+def test_default_columns(built, iri):
+    rows = built.to_table()
+
+    assert [row["iri"] for row in rows] == [iri("A"), iri("B"), iri("C")]
+    assert rows[0] == {
+        "iri": iri("A"),
+        "curie": "ex:A",
+        "label": "a",
+        "definition": 'An "A", with a comma',
+        "parents": "b",
+    }
+    assert rows[1]["definition"] == "A B."
+    assert rows[1]["parents"] == ""
+
+
+def test_annotation_property_columns(built, iri):
+    for column in ["note", "ex:note", iri("note")]:
+        assert built.to_table(columns=["label", column])[0] == {"label": "a", column: "first|second"}
+
+
+def test_class_labels_are_not_columns(built):
+    with pytest.raises(ValueError):
+        built.to_table(columns=["c label"])
+
+
+def test_unknown_column(built):
+    with pytest.raises(ValueError):
+        built.to_table(columns=["iri", "nothing"])
+
+
+def test_csv_quoting(built, tmp_path):
+    path = tmp_path / "classes.csv"
+    rows = built.to_table(columns=["iri", "definition"], path=str(path))
+
+    with open(path, newline="") as f:
+        written = list(csv.DictReader(f))
+    assert written == rows
+    assert '"An ""A"", with a comma"' in path.read_text()
+
+
+def test_tsv(built, iri, tmp_path):
+    path = tmp_path / "classes.tsv"
+    rows = built.to_table(columns=["iri", "label"], path=str(path))
+
+    lines = path.read_text().splitlines()
+    assert lines[0] == "iri\tlabel"
+    assert lines[3] == f"{iri('C')}\tc label"
+    with open(path, newline="") as f:
+        assert list(csv.DictReader(f, delimiter="\t")) == rows